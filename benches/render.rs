@@ -0,0 +1,47 @@
+// Times the default scene, run with `cargo bench` and compare the numbers between changes.
+use rustracer::demo;
+use rustracer::intersection::*;
+use rustracer::{render, RenderSettings};
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+const RUNS: u32 = 5;
+
+// Best time out of a few runs, the least disturbed by whatever else the machine is doing.
+fn bench<F: FnMut()>(name: &str, mut f: F) {
+    f();
+    let best = (0..RUNS)
+        .map(|_| {
+            let start = Instant::now();
+            f();
+            start.elapsed()
+        })
+        .min()
+        .unwrap_or(Duration::ZERO);
+    println!("{:<16} {:>10.2} ms", name, best.as_secs_f64() * 1000.0);
+}
+
+fn main() {
+    let (cam, world) = demo::get_scene();
+    println!(
+        "default scene, {}x{}, best of {} runs",
+        cam.hsize, cam.vsize, RUNS
+    );
+    bench("camera rays", || {
+        for y in 0..cam.vsize {
+            for x in 0..cam.hsize {
+                black_box(cam.ray_for_pixel(x, y));
+            }
+        }
+    });
+    bench("primary hits", || {
+        for y in 0..cam.vsize {
+            for x in 0..cam.hsize {
+                black_box(hit(intersect_world(&cam.ray_for_pixel(x, y), &world)));
+            }
+        }
+    });
+    bench("render", || {
+        black_box(render(&cam, &world, &RenderSettings::default()));
+    });
+}
@@ -0,0 +1,308 @@
+use crate::camera::*;
+use crate::color::*;
+use crate::matrix::*;
+use crate::motion::*;
+use crate::point::*;
+use crate::vector3::*;
+use crate::world::*;
+use std::sync::Arc;
+
+// Values that can be keyed, interpolation only needs weighted sums of two values.
+pub trait Keyable: Copy {
+    fn combine(&self, other: &Self, self_weight: f64, other_weight: f64) -> Self;
+}
+
+impl Keyable for f64 {
+    fn combine(&self, other: &Self, self_weight: f64, other_weight: f64) -> Self {
+        self * self_weight + other * other_weight
+    }
+}
+
+impl Keyable for f32 {
+    fn combine(&self, other: &Self, self_weight: f64, other_weight: f64) -> Self {
+        (*self as f64 * self_weight + *other as f64 * other_weight) as f32
+    }
+}
+
+impl Keyable for Point {
+    fn combine(&self, other: &Self, self_weight: f64, other_weight: f64) -> Self {
+        Point {
+            x: self.x.combine(&other.x, self_weight, other_weight),
+            y: self.y.combine(&other.y, self_weight, other_weight),
+            z: self.z.combine(&other.z, self_weight, other_weight),
+        }
+    }
+}
+
+impl Keyable for Vector3 {
+    fn combine(&self, other: &Self, self_weight: f64, other_weight: f64) -> Self {
+        Vector3 {
+            x: self.x.combine(&other.x, self_weight, other_weight),
+            y: self.y.combine(&other.y, self_weight, other_weight),
+            z: self.z.combine(&other.z, self_weight, other_weight),
+        }
+    }
+}
+
+impl Keyable for Color {
+    fn combine(&self, other: &Self, self_weight: f64, other_weight: f64) -> Self {
+        Color {
+            red: self.red.combine(&other.red, self_weight, other_weight),
+            green: self.green.combine(&other.green, self_weight, other_weight),
+            blue: self.blue.combine(&other.blue, self_weight, other_weight),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum KeyInterpolation {
+    // Straight line to the next key.
+    Linear,
+    // Cubic Bezier to the next key, handles are placed automatically from the neighbouring keys
+    // so the motion is smooth through every key.
+    Bezier,
+    // Holds the value until the next key.
+    Step,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Keyframe<T: Keyable> {
+    pub time: f64,
+    pub value: T,
+    // How the value travels from this key to the next one.
+    pub interpolation: KeyInterpolation,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Track<T: Keyable> {
+    pub keys: Vec<Keyframe<T>>,
+}
+
+impl<T: Keyable> Track<T> {
+    pub fn new() -> Self {
+        Track { keys: vec![] }
+    }
+
+    pub fn key(mut self, time: f64, value: T, interpolation: KeyInterpolation) -> Self {
+        let index = self.keys.partition_point(|k| k.time <= time);
+        self.keys.insert(
+            index,
+            Keyframe {
+                time,
+                value,
+                interpolation,
+            },
+        );
+        self
+    }
+
+    // Value of the track at the given time, held constant before the first and after the last key.
+    pub fn value_at(&self, time: f64) -> Option<T> {
+        let first = self.keys.first()?;
+        let last = self.keys.last()?;
+        if time <= first.time {
+            return Some(first.value);
+        }
+        if time >= last.time {
+            return Some(last.value);
+        }
+        let next = self.keys.partition_point(|k| k.time <= time);
+        let (k0, k1) = (&self.keys[next - 1], &self.keys[next]);
+        let t = (time - k0.time) / (k1.time - k0.time);
+        let value = match k0.interpolation {
+            KeyInterpolation::Step => k0.value,
+            KeyInterpolation::Linear => k0.value.combine(&k1.value, 1.0 - t, t),
+            KeyInterpolation::Bezier => {
+                let previous = if next >= 2 { &self.keys[next - 2] } else { k0 };
+                let following = self.keys.get(next + 1).unwrap_or(k1);
+                // Catmull-Rom tangents, scaled to the segment length, turned into Bezier handles.
+                let duration = k1.time - k0.time;
+                let w0 = duration / (k1.time - previous.time) / 3.0;
+                let w1 = duration / (following.time - k0.time) / 3.0;
+                let h0 = k0
+                    .value
+                    .combine(&delta(&previous.value, &k1.value), 1.0, w0);
+                let h1 = k1
+                    .value
+                    .combine(&delta(&k0.value, &following.value), 1.0, -w1);
+                bezier(&k0.value, &h0, &h1, &k1.value, t)
+            }
+        };
+        Some(value)
+    }
+}
+
+impl<T: Keyable> Default for Track<T> {
+    fn default() -> Self {
+        Track::new()
+    }
+}
+
+fn delta<T: Keyable>(from: &T, to: &T) -> T {
+    to.combine(from, 1.0, -1.0)
+}
+
+fn bezier<T: Keyable>(p0: &T, p1: &T, p2: &T, p3: &T, t: f64) -> T {
+    let u = 1.0 - t;
+    let a = p0.combine(p1, u * u * u, 3.0 * u * u * t);
+    let b = p2.combine(p3, 3.0 * u * t * t, t * t * t);
+    a.combine(&b, 1.0, 1.0)
+}
+
+// Keyed camera placement, turned into a view transform.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct CameraKey {
+    pub from: Point,
+    pub to: Point,
+    pub up: Vector3,
+    pub field_of_view: f64,
+}
+
+impl Keyable for CameraKey {
+    fn combine(&self, other: &Self, self_weight: f64, other_weight: f64) -> Self {
+        CameraKey {
+            from: self.from.combine(&other.from, self_weight, other_weight),
+            to: self.to.combine(&other.to, self_weight, other_weight),
+            up: self.up.combine(&other.up, self_weight, other_weight),
+            field_of_view: self.field_of_view.combine(
+                &other.field_of_view,
+                self_weight,
+                other_weight,
+            ),
+        }
+    }
+}
+
+impl CameraKey {
+    pub fn camera(&self, hsize: u32, vsize: u32) -> Camera {
+        let mut cam = Camera::new(hsize, vsize, self.field_of_view);
+        cam.set_transform(view_transform(self.from, self.to, self.up));
+        cam
+    }
+}
+
+// Keyed object transform, split in components so rotations interpolate without shrinking.
+// The matrix is built as scale, then rotation around x, y and z (radians), then translation.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct TransformKey {
+    pub translation: Vector3,
+    pub rotation: Vector3,
+    pub scale: Vector3,
+}
+
+impl Keyable for TransformKey {
+    fn combine(&self, other: &Self, self_weight: f64, other_weight: f64) -> Self {
+        TransformKey {
+            translation: self
+                .translation
+                .combine(&other.translation, self_weight, other_weight),
+            rotation: self
+                .rotation
+                .combine(&other.rotation, self_weight, other_weight),
+            scale: self.scale.combine(&other.scale, self_weight, other_weight),
+        }
+    }
+}
+
+impl TransformKey {
+    pub fn matrix(&self) -> Matrix44 {
+        Matrix44::scaling(self.scale.x, self.scale.y, self.scale.z)
+            .rotate_x(self.rotation.x)
+            .rotate_y(self.rotation.y)
+            .rotate_z(self.rotation.z)
+            .translate(self.translation.x, self.translation.y, self.translation.z)
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum MaterialChannel {
+    Ambient,
+    Diffuse,
+    Specular,
+    Shininess,
+    Reflective,
+    Transparency,
+    RefractiveIndex,
+}
+
+// Tracks are bound to objects and lights by their index in the world.
+#[derive(Clone, Debug, Default)]
+pub struct Animation {
+    pub camera: Option<Track<CameraKey>>,
+    pub objects: Vec<(usize, Track<TransformKey>)>,
+    pub lights: Vec<(usize, Track<Point>)>,
+    pub materials: Vec<(usize, MaterialChannel, Track<f32>)>,
+    pub colors: Vec<(usize, Track<Color>)>,
+}
+
+impl Animation {
+    // Poses the camera and the world at the given time. With a shutter duration above zero, animated
+    // camera and objects also get a motion ending where they are `shutter` seconds later, for motion blur.
+    pub fn apply(&self, cam: &mut Camera, world: &mut World, time: f64, shutter: f64) {
+        if let Some(key) = self.camera.as_ref().and_then(|t| t.value_at(time)) {
+            let motion = cam.motion;
+            *cam = key.camera(cam.hsize, cam.vsize);
+            cam.motion = motion;
+            if shutter > 0.0 {
+                if let Some(end) = self
+                    .camera
+                    .as_ref()
+                    .and_then(|t| t.value_at(time + shutter))
+                {
+                    cam.motion = Some(Motion::new(
+                        view_transform(end.from, end.to, end.up),
+                        Interpolation::Quaternion,
+                    ));
+                }
+            }
+        }
+        for (index, track) in &self.objects {
+            if let (Some(key), Some(object)) = (track.value_at(time), world.objects.get(*index)) {
+                let mut object = (**object).clone().set_transform(key.matrix());
+                if shutter > 0.0 {
+                    if let Some(end) = track.value_at(time + shutter) {
+                        object = object.set_motion(end.matrix(), Interpolation::Quaternion);
+                    }
+                }
+                world.objects[*index] = Arc::new(object);
+            }
+        }
+        for (index, track) in &self.lights {
+            if let (Some(position), Some(light)) =
+                (track.value_at(time), world.lights.get_mut(*index))
+            {
+                light.set_position(position);
+            }
+        }
+        for (index, channel, track) in &self.materials {
+            if let (Some(value), Some(object)) =
+                (track.value_at(time), world.objects.get_mut(*index))
+            {
+                let mut material = object.material().clone();
+                match channel {
+                    MaterialChannel::Ambient => material.ambient = value,
+                    MaterialChannel::Diffuse => material.diffuse = value,
+                    MaterialChannel::Specular => material.specular = value,
+                    MaterialChannel::Shininess => material.shininess = value,
+                    MaterialChannel::Reflective => material.reflective = value,
+                    MaterialChannel::Transparency => material.transparency = value,
+                    MaterialChannel::RefractiveIndex => material.refractive_index = value,
+                }
+                Arc::make_mut(object).set_material(material);
+            }
+        }
+        for (index, track) in &self.colors {
+            if let (Some(color), Some(object)) =
+                (track.value_at(time), world.objects.get_mut(*index))
+            {
+                let mut material = object.material().clone();
+                material.color = color;
+                Arc::make_mut(object).set_material(material);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+#[path = "./animation_tests.rs"]
+mod animation_tests;
@@ -0,0 +1,213 @@
+#[cfg(test)]
+mod tests {
+    use crate::animation::*;
+    use crate::material::*;
+    use crate::objects::sphere::*;
+    use crate::objects::*;
+
+    #[test]
+    fn test_empty_track() {
+        let track: Track<f64> = Track::new();
+        assert_eq!(track.value_at(1.0), None);
+    }
+
+    #[test]
+    fn test_track_holds_outside_keys() {
+        let track = Track::new().key(1.0, 2.0, KeyInterpolation::Linear).key(
+            2.0,
+            4.0,
+            KeyInterpolation::Linear,
+        );
+        assert_eq!(track.value_at(0.0), Some(2.0));
+        assert_eq!(track.value_at(3.0), Some(4.0));
+    }
+
+    #[test]
+    fn test_keys_are_sorted() {
+        let track = Track::new().key(2.0, 4.0, KeyInterpolation::Linear).key(
+            1.0,
+            2.0,
+            KeyInterpolation::Linear,
+        );
+        assert_eq!(track.keys[0].time, 1.0);
+        assert_eq!(track.value_at(1.5), Some(3.0));
+    }
+
+    #[test]
+    fn test_step_interpolation() {
+        let track = Track::new().key(0.0, 1.0, KeyInterpolation::Step).key(
+            1.0,
+            5.0,
+            KeyInterpolation::Step,
+        );
+        assert_eq!(track.value_at(0.99), Some(1.0));
+        assert_eq!(track.value_at(1.0), Some(5.0));
+    }
+
+    #[test]
+    fn test_bezier_interpolation() {
+        let track = Track::new()
+            .key(0.0, 0.0_f64, KeyInterpolation::Bezier)
+            .key(1.0, 1.0, KeyInterpolation::Bezier)
+            .key(2.0, 0.0, KeyInterpolation::Bezier);
+        assert_eq!(track.value_at(0.0), Some(0.0));
+        assert_eq!(track.value_at(1.0), Some(1.0));
+        // Goes through the keys but eases around the peak instead of turning sharply.
+        let before = track.value_at(0.9).unwrap();
+        let after = track.value_at(1.1).unwrap();
+        assert!((before - after).abs() < 1e-9);
+        assert!(before > 0.9 && before < 1.0);
+        // Evenly spaced keys on a line stay on the line.
+        let line = Track::new()
+            .key(0.0, 0.0_f64, KeyInterpolation::Bezier)
+            .key(1.0, 1.0, KeyInterpolation::Bezier)
+            .key(2.0, 2.0, KeyInterpolation::Bezier);
+        assert!((line.value_at(1.25).unwrap() - 1.25).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_keyed_point() {
+        let track = Track::new()
+            .key(0.0, Point::zero(), KeyInterpolation::Linear)
+            .key(
+                2.0,
+                Point {
+                    x: 2.0,
+                    y: 4.0,
+                    z: -2.0,
+                },
+                KeyInterpolation::Linear,
+            );
+        assert_eq!(
+            track.value_at(1.0),
+            Some(Point {
+                x: 1.0,
+                y: 2.0,
+                z: -1.0,
+            })
+        );
+    }
+
+    #[test]
+    fn test_transform_key_matrix() {
+        let key = TransformKey {
+            translation: Vector3 {
+                x: 1.0,
+                y: 2.0,
+                z: 3.0,
+            },
+            rotation: Vector3 {
+                x: 0.0,
+                y: 0.5,
+                z: 0.0,
+            },
+            scale: Vector3 {
+                x: 2.0,
+                y: 2.0,
+                z: 2.0,
+            },
+        };
+        assert_eq!(
+            key.matrix(),
+            Matrix44::scaling(2.0, 2.0, 2.0)
+                .rotate_y(0.5)
+                .translate(1.0, 2.0, 3.0)
+        );
+    }
+
+    #[test]
+    fn test_apply_animation() {
+        let mut world = World::default();
+        let mut cam = Camera::new(10, 10, 1.0);
+        let key = |x: f64| TransformKey {
+            translation: Vector3 { x, y: 0.0, z: 0.0 },
+            rotation: Vector3 {
+                x: 0.0,
+                y: 0.0,
+                z: 0.0,
+            },
+            scale: Vector3 {
+                x: 1.0,
+                y: 1.0,
+                z: 1.0,
+            },
+        };
+        let animation = Animation {
+            objects: vec![(
+                0,
+                Track::new()
+                    .key(0.0, key(0.0), KeyInterpolation::Linear)
+                    .key(1.0, key(4.0), KeyInterpolation::Linear),
+            )],
+            lights: vec![(
+                0,
+                Track::new().key(0.0, Point::zero(), KeyInterpolation::Linear),
+            )],
+            materials: vec![(
+                1,
+                MaterialChannel::Reflective,
+                Track::new().key(0.0, 0.5, KeyInterpolation::Linear),
+            )],
+            colors: vec![(
+                1,
+                Track::new().key(0.0, Color::green(), KeyInterpolation::Linear),
+            )],
+            camera: Some(Track::new().key(
+                0.0,
+                CameraKey {
+                    from: Point {
+                        x: 0.0,
+                        y: 0.0,
+                        z: -5.0,
+                    },
+                    to: Point::zero(),
+                    up: Vector3 {
+                        x: 0.0,
+                        y: 1.0,
+                        z: 0.0,
+                    },
+                    field_of_view: 2.0,
+                },
+                KeyInterpolation::Linear,
+            )),
+        };
+        animation.apply(&mut cam, &mut world, 0.5, 0.0);
+        assert_eq!(
+            world.objects[0].transform(),
+            &Matrix44::translation(2.0, 0.0, 0.0)
+        );
+        assert_eq!(world.objects[0].motion(), None);
+        assert_eq!(world.lights[0].position(), Point::zero());
+        assert_eq!(world.objects[1].material().reflective, 0.5);
+        assert_eq!(world.objects[1].material().color, Color::green());
+        assert_eq!(cam.field_of_view, 2.0);
+        assert_eq!(cam.hsize, 10);
+        assert_eq!(
+            cam.position(),
+            Point {
+                x: 0.0,
+                y: 0.0,
+                z: -5.0,
+            }
+        );
+
+        animation.apply(&mut cam, &mut world, 0.5, 0.25);
+        let motion = world.objects[0].motion().unwrap();
+        assert_eq!(motion.end, Matrix44::translation(3.0, 0.0, 0.0));
+    }
+
+    #[test]
+    fn test_apply_ignores_missing_objects() {
+        let mut world = World::new(vec![Object::new(Sphere, Material::default())], vec![]);
+        let mut cam = Camera::new(10, 10, 1.0);
+        let animation = Animation {
+            lights: vec![(
+                3,
+                Track::new().key(0.0, Point::zero(), KeyInterpolation::Linear),
+            )],
+            ..Animation::default()
+        };
+        animation.apply(&mut cam, &mut world, 0.0, 0.0);
+        assert!(world.lights.is_empty());
+    }
+}
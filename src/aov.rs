@@ -0,0 +1,264 @@
+use crate::camera::*;
+use crate::color::*;
+use crate::framebuffer::*;
+use crate::intersection::*;
+use crate::ray::*;
+use crate::render::*;
+use crate::stats;
+use crate::tonemap::*;
+use crate::vector3::*;
+use crate::world::*;
+use image::ImageResult;
+use std::path::Path;
+
+// Arbitrary output variables, extra images rendered alongside the beauty pass.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Aov {
+    // Distance from the eye along the camera axis.
+    Depth,
+    // World space normal, facing the eye.
+    Normal,
+    // Surface color before lighting.
+    Albedo,
+    // Index of the object in the world plus one, 0 where nothing was hit.
+    ObjectId,
+    Direct,
+    Reflected,
+    Refracted,
+}
+
+impl Aov {
+    pub fn all() -> [Aov; 7] {
+        [
+            Aov::Depth,
+            Aov::Normal,
+            Aov::Albedo,
+            Aov::ObjectId,
+            Aov::Direct,
+            Aov::Reflected,
+            Aov::Refracted,
+        ]
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Aov::Depth => "depth",
+            Aov::Normal => "normal",
+            Aov::Albedo => "albedo",
+            Aov::ObjectId => "object_id",
+            Aov::Direct => "direct",
+            Aov::Reflected => "reflected",
+            Aov::Refracted => "refracted",
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<Aov> {
+        Aov::all().into_iter().find(|aov| aov.name() == name)
+    }
+
+    // Light passes add up to the beauty image and get the same exposure and tone mapping.
+    pub fn is_light(&self) -> bool {
+        matches!(self, Aov::Direct | Aov::Reflected | Aov::Refracted)
+    }
+
+    // Viewable version of a pass for 8 bit files, float files keep the raw values. Depth goes from
+    // white up close to dark gray at the far end, normals are remapped from [-1, 1] to [0, 1] and
+    // every object gets its own color.
+    pub fn display(&self, framebuffer: &Framebuffer) -> Framebuffer {
+        let far = far_depth(framebuffer);
+        let pixels = framebuffer
+            .pixels
+            .iter()
+            .map(|c| match self {
+                Aov::Depth if c.red > 0.0 => {
+                    (Color::white() * (1.0 - 0.9 * (c.red / far).min(1.0))).to_linear()
+                }
+                Aov::Depth => Color::black(),
+                Aov::Normal if *c != Color::black() => (*c * 0.5 + 0.5).to_linear(),
+                Aov::Normal => Color::black(),
+                Aov::ObjectId => id_color(c.red as u32),
+                _ => *c,
+            })
+            .collect();
+        Framebuffer {
+            width: framebuffer.width,
+            height: framebuffer.height,
+            pixels,
+        }
+    }
+}
+
+// Depth most of the surfaces are in front of, planes going to the horizon would squash everything
+// else against white if the furthest depth was used.
+fn far_depth(framebuffer: &Framebuffer) -> f32 {
+    let mut depths: Vec<f32> = framebuffer
+        .pixels
+        .iter()
+        .map(|c| c.red)
+        .filter(|d| *d > 0.0)
+        .collect();
+    if depths.is_empty() {
+        return 1.0;
+    }
+    depths.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    depths[(depths.len() - 1) * 95 / 100]
+}
+
+// Distinct colors for neighbouring ids, hues are spread by the golden angle.
+pub fn id_color(id: u32) -> Color {
+    if id == 0 {
+        return Color::black();
+    }
+    Color::from_hsv(id as f32 * 137.508, 0.65, 0.9)
+}
+
+// What the primary ray of a sample sees.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct AovSample {
+    pub depth: f64,
+    pub normal: Vector3,
+    pub albedo: Color,
+    pub object_id: u32,
+    pub contributions: ShadeContributions,
+}
+
+impl AovSample {
+    pub fn background() -> Self {
+        AovSample {
+            depth: 0.0,
+            normal: Vector3 {
+                x: 0.0,
+                y: 0.0,
+                z: 0.0,
+            },
+            albedo: Color::black(),
+            object_id: 0,
+            contributions: ShadeContributions {
+                direct: Color::black(),
+                reflected: Color::black(),
+                refracted: Color::black(),
+            },
+        }
+    }
+}
+
+pub fn sample_aovs(cam: &Camera, world: &World, ray: &Ray, remaining: i32) -> AovSample {
+    let (index, comps) = match hit_indexed(ray, world) {
+        Some(hit) => hit,
+        None => return AovSample::background(),
+    };
+    AovSample {
+        depth: -(cam.transform_at(ray.time) * comps.point).z,
+        normal: comps.normalv,
+        albedo: comps
+            .object
+            .material()
+            .color_at(&comps.object, &comps.point),
+        object_id: index as u32 + 1,
+        contributions: shade_hit_contributions(world, &comps, remaining),
+    }
+}
+
+// Every pass of a render, samples are averaged except for object ids which come from the first one.
+pub struct Aovs {
+    pub depth: Framebuffer,
+    pub normal: Framebuffer,
+    pub albedo: Framebuffer,
+    pub object_id: Framebuffer,
+    pub direct: Framebuffer,
+    pub reflected: Framebuffer,
+    pub refracted: Framebuffer,
+}
+
+impl Aovs {
+    pub fn new(width: u32, height: u32) -> Self {
+        Aovs {
+            depth: Framebuffer::new(width, height),
+            normal: Framebuffer::new(width, height),
+            albedo: Framebuffer::new(width, height),
+            object_id: Framebuffer::new(width, height),
+            direct: Framebuffer::new(width, height),
+            reflected: Framebuffer::new(width, height),
+            refracted: Framebuffer::new(width, height),
+        }
+    }
+
+    pub fn get(&self, aov: Aov) -> &Framebuffer {
+        match aov {
+            Aov::Depth => &self.depth,
+            Aov::Normal => &self.normal,
+            Aov::Albedo => &self.albedo,
+            Aov::ObjectId => &self.object_id,
+            Aov::Direct => &self.direct,
+            Aov::Reflected => &self.reflected,
+            Aov::Refracted => &self.refracted,
+        }
+    }
+
+    // Sum of the light passes.
+    pub fn beauty(&self) -> Framebuffer {
+        let mut beauty = self.direct.clone();
+        for (i, pixel) in beauty.pixels.iter_mut().enumerate() {
+            *pixel = *pixel + self.reflected.pixels[i] + self.refracted.pixels[i];
+        }
+        beauty
+    }
+
+    // Writes a pass, light passes go through the post process like the beauty image.
+    pub fn save<P: AsRef<Path>>(
+        &self,
+        aov: Aov,
+        path: P,
+        post_process: &PostProcess,
+    ) -> ImageResult<()> {
+        if aov.is_light() {
+            return self.get(aov).save(path, post_process);
+        }
+        let plain = PostProcess {
+            dither: post_process.dither,
+            ..PostProcess::default()
+        };
+        if is_float_format(&path) {
+            self.get(aov).save(path, &plain)
+        } else {
+            aov.display(self.get(aov)).save(path, &plain)
+        }
+    }
+}
+
+pub fn render_aovs(cam: &Camera, world: &World, settings: &RenderSettings) -> Aovs {
+    let mut aovs = Aovs::new(cam.hsize, cam.vsize);
+    let samples = settings.samples.max(1);
+    let weight = 1.0 / samples as f32;
+    for y in 0..cam.vsize {
+        for x in 0..cam.hsize {
+            let index = (y * cam.hsize + x) as usize;
+            for sample in 0..samples {
+                let ray = settings.camera_ray(cam, x, y, sample);
+                let s = stats::primary(|| sample_aovs(cam, world, &ray, settings.max_recursion));
+                let normal = Color {
+                    red: s.normal.x as f32,
+                    green: s.normal.y as f32,
+                    blue: s.normal.z as f32,
+                };
+                let accumulate = |framebuffer: &mut Framebuffer, color: Color| {
+                    framebuffer.pixels[index] = framebuffer.pixels[index] + color * weight;
+                };
+                accumulate(&mut aovs.depth, Color::white() * s.depth as f32);
+                accumulate(&mut aovs.normal, normal);
+                accumulate(&mut aovs.albedo, s.albedo);
+                accumulate(&mut aovs.direct, s.contributions.direct);
+                accumulate(&mut aovs.reflected, s.contributions.reflected);
+                accumulate(&mut aovs.refracted, s.contributions.refracted);
+                if sample == 0 {
+                    aovs.object_id.pixels[index] = Color::white() * s.object_id as f32;
+                }
+            }
+        }
+    }
+    aovs
+}
+
+#[cfg(test)]
+#[path = "./aov_tests.rs"]
+mod aov_tests;
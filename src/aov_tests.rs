@@ -0,0 +1,100 @@
+#[cfg(test)]
+mod tests {
+    use crate::aov::*;
+    use crate::material::*;
+    use crate::matrix::*;
+    use crate::objects::plane::*;
+    use crate::objects::*;
+    use std::sync::Arc;
+
+    #[test]
+    fn test_names() {
+        for aov in Aov::all() {
+            assert_eq!(Aov::from_name(aov.name()), Some(aov));
+        }
+        assert_eq!(Aov::from_name("beauty"), None);
+    }
+
+    #[test]
+    fn test_sample_hit() {
+        let cam = Camera::test_camera(11, std::f64::consts::FRAC_PI_2);
+        let world = World::default();
+        let ray = cam.ray_for_pixel(5, 5);
+        let sample = sample_aovs(&cam, &world, &ray, 5);
+        assert!((sample.depth - 4.0).abs() < 1e-9);
+        assert_eq!(
+            sample.normal,
+            Vector3 {
+                x: 0.0,
+                y: 0.0,
+                z: -1.0,
+            }
+        );
+        assert_eq!(sample.object_id, 1);
+        assert_eq!(sample.albedo, world.objects[0].material().color);
+        assert_eq!(sample.contributions.total(), color_at(&world, &ray, 5));
+        assert_eq!(sample.contributions.reflected, Color::black());
+    }
+
+    #[test]
+    fn test_sample_background() {
+        let cam = Camera::test_camera(11, std::f64::consts::FRAC_PI_2);
+        let ray = cam.ray_for_pixel(0, 0);
+        let sample = sample_aovs(&cam, &World::new(vec![], vec![]), &ray, 5);
+        assert_eq!(sample, AovSample::background());
+    }
+
+    #[test]
+    fn test_depth_is_along_camera_axis() {
+        let cam = Camera::test_camera(11, std::f64::consts::FRAC_PI_2);
+        let world = World::new(
+            vec![Object::new(Plane, Material::default()).set_transform(
+                Matrix44::rotation_x(std::f64::consts::FRAC_PI_2).translate(0.0, 0.0, 1.0),
+            )],
+            World::default().lights,
+        );
+        // A wall facing the camera has the same depth everywhere.
+        let center = sample_aovs(&cam, &world, &cam.ray_for_pixel(5, 5), 5);
+        let corner = sample_aovs(&cam, &world, &cam.ray_for_pixel(0, 0), 5);
+        assert!((center.depth - 6.0).abs() < 1e-9);
+        assert!((corner.depth - 6.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_light_passes_add_up_to_render() {
+        let cam = Camera::test_camera(11, std::f64::consts::FRAC_PI_2);
+        let mut world = World::default();
+        let mut material = world.objects[1].material().clone();
+        material.reflective = 0.5;
+        Arc::make_mut(&mut world.objects[1]).set_material(material);
+        let settings = RenderSettings::default();
+        let aovs = render_aovs(&cam, &world, &settings);
+        assert_eq!(aovs.beauty(), render(&cam, &world, &settings));
+        assert_eq!(aovs.object_id.get(5, 5), Color::white());
+        assert_eq!(aovs.object_id.get(0, 0), Color::black());
+    }
+
+    #[test]
+    fn test_display() {
+        let mut fb = Framebuffer::new(3, 1);
+        fb.set(
+            1,
+            0,
+            Color {
+                red: 0.0,
+                green: 0.0,
+                blue: -1.0,
+            },
+        );
+        fb.set(2, 0, Color::white());
+        let normals = Aov::Normal.display(&fb).to_rgba8(false);
+        assert_eq!(normals.get_pixel(0, 0).0, [0, 0, 0, 255]);
+        assert_eq!(normals.get_pixel(1, 0).0, [128, 128, 0, 255]);
+        assert_eq!(normals.get_pixel(2, 0).0, [255, 255, 255, 255]);
+        let ids = Aov::ObjectId.display(&fb);
+        assert_eq!(ids.get(0, 0), Color::black());
+        assert_ne!(ids.get(2, 0), Color::black());
+        // Light passes are left alone.
+        assert_eq!(Aov::Direct.display(&fb), fb);
+    }
+}
@@ -0,0 +1,216 @@
+use pixels::{Error, Pixels, SurfaceTexture};
+use rustracer::camera::*;
+use rustracer::cli::*;
+use rustracer::debug_view::*;
+use rustracer::demo::*;
+use rustracer::inspect::*;
+use rustracer::intersection::*;
+use rustracer::navigation::*;
+use rustracer::preview::*;
+use rustracer::render::*;
+use rustracer::scene::*;
+use rustracer::world::*;
+use std::env;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use winit::dpi::LogicalSize;
+use winit::event::{Event, VirtualKeyCode};
+use winit::event_loop::{ControlFlow, EventLoop};
+use winit::window::WindowBuilder;
+use winit_input_helper::WinitInputHelper;
+
+// Interactive preview window, renders progressively and reloads `--scene` files when they change.
+fn main() -> Result<(), Error> {
+    let args: Vec<String> = env::args().collect();
+    let (cam, world) = scene_from_args(&args);
+    let mut settings = render_settings(&args);
+    let scene_path = arg_value(&args, "--scene");
+    let mut input = WinitInputHelper::new();
+    let event_loop = EventLoop::new();
+    // Fraction of the window resolution the preview renders at, below 1.0 for faster feedback.
+    let render_scale: f64 = arg_value(&args, "--render-scale")
+        .map(|v| v.parse().expect("--render-scale expects a number"))
+        .unwrap_or(1.0);
+    let window = WindowBuilder::new()
+        .with_title("Rustracer")
+        .with_inner_size(LogicalSize::new(WIDTH as f64, HEIGHT as f64))
+        .with_min_inner_size(LogicalSize::new(64.0, 64.0))
+        .build(&event_loop)
+        .unwrap();
+    // The buffer follows the physical size of the window, so HiDPI screens get every pixel.
+    let window_size = window.inner_size();
+    let (width, height) = buffer_size(window_size.width, window_size.height, render_scale);
+    let cam = cam.resized(width, height);
+    let surface_texture = SurfaceTexture::new(window_size.width, window_size.height, &window);
+    let mut pixels = Pixels::new(width, height, surface_texture)?;
+    let mut world = Arc::new(world);
+    let preview = Preview::new(cam.clone(), Arc::clone(&world), settings.clone());
+    let mut cam = cam;
+    let mut navigation = Navigation::from_camera(&cam, focus_distance(&cam, &world, &settings));
+    let mut last_update = Instant::now();
+    // Saving the scene file swaps the world, the view only jumps when the camera line changed.
+    let mut watcher = scene_path.map(SceneWatcher::new);
+    let mut scene_cam = cam.clone();
+    let mut scene_error: Option<String> = None;
+    event_loop.run(move |event, _, control_flow| {
+        if let Event::RedrawRequested(_) = event {
+            if pixels.render().is_err() {
+                *control_flow = ControlFlow::Exit;
+                return;
+            }
+        }
+        if input.update(&event) {
+            if input.key_released(VirtualKeyCode::Escape) || input.quit() {
+                *control_flow = ControlFlow::Exit;
+                return;
+            }
+            let elapsed = last_update.elapsed().as_secs_f64();
+            last_update = Instant::now();
+            let mut restart = false;
+            if navigate(&input, &mut navigation, elapsed) {
+                cam.set_transform(navigation.transform());
+                restart = true;
+            }
+            if let Some(size) = input.window_resized() {
+                // Minimized windows report a zero size, there is nothing to draw then.
+                if size.width > 0 && size.height > 0 {
+                    let (width, height) = buffer_size(size.width, size.height, render_scale);
+                    pixels.resize_surface(size.width, size.height);
+                    pixels.resize_buffer(width, height);
+                    cam = cam.resized(width, height);
+                    restart = true;
+                }
+            }
+            if let Some(watcher) = watcher.as_mut() {
+                if watcher.changed() {
+                    match load_scene(watcher.path(), cam.hsize, cam.vsize) {
+                        Ok((new_cam, new_world)) => {
+                            world = Arc::new(new_world);
+                            if new_cam.transform() != scene_cam.transform()
+                                || new_cam.field_of_view != scene_cam.field_of_view
+                            {
+                                cam = new_cam.clone();
+                                navigation = Navigation::from_camera(
+                                    &cam,
+                                    focus_distance(&cam, &world, &settings),
+                                );
+                            }
+                            scene_cam = new_cam;
+                            scene_error = None;
+                            println!("reloaded {}", watcher.path().display());
+                            restart = true;
+                        }
+                        Err(e) => {
+                            // The previous scene stays on screen until the file parses again.
+                            let message = format!("{}: {}", watcher.path().display(), e);
+                            eprintln!("{}", message);
+                            window.set_title(&format!("Rustracer - {}", message));
+                            scene_error = Some(message);
+                        }
+                    }
+                }
+            }
+            if restart {
+                preview.restart(cam.clone(), Arc::clone(&world), settings.clone());
+            }
+            // Ctrl click prints how the pixel under the mouse was shaded, with shift the whole ray tree.
+            if input.held_control() && input.mouse_pressed(0) {
+                if let Some(Ok((x, y))) = input.mouse().map(|pos| pixels.window_pos_to_pixel(pos)) {
+                    println!("pixel ({}, {})", x, y);
+                    print!(
+                        "{}",
+                        inspect_ray(
+                            &world,
+                            &settings.camera_ray(&cam, x as u32, y as u32, 0),
+                            settings.max_recursion,
+                            input.held_shift(),
+                        )
+                    );
+                }
+            }
+            if let Some(view) = pick_view(&input) {
+                settings.view = view;
+                preview.restart(cam.clone(), Arc::clone(&world), settings.clone());
+            }
+            if input.key_pressed(VirtualKeyCode::C) {
+                let line = navigation.scene_line(cam.field_of_view);
+                println!("{}", line);
+                if let Err(e) = std::fs::write("camera.txt", line + "\n") {
+                    eprintln!("could not save camera.txt: {}", e);
+                }
+            }
+            // Passes started before a resize are dropped.
+            if let Some(frame) = preview
+                .poll()
+                .filter(|f| f.image.width == cam.hsize && f.image.height == cam.vsize)
+            {
+                let image = settings.display().to_rgba8(&frame.image);
+                pixels.get_frame().copy_from_slice(image.as_raw());
+                window.set_title(&match &scene_error {
+                    Some(message) => format!("Rustracer - {}", message),
+                    None => frame.status(),
+                });
+                window.request_redraw();
+            }
+            // Passes are picked up at about 60 frames per second without spinning.
+            *control_flow = ControlFlow::WaitUntil(Instant::now() + Duration::from_millis(16));
+        }
+    });
+}
+// Distance to whatever is in the middle of the view, the camera orbits around it.
+fn focus_distance(cam: &Camera, world: &World, settings: &RenderSettings) -> f64 {
+    hit(intersect_world(
+        &settings.camera_ray(cam, cam.hsize / 2, cam.vsize / 2, 0),
+        world,
+    ))
+    .map(|h| h.distance)
+    .unwrap_or(5.0)
+}
+
+// Preview resolution for a window size, never below one pixel.
+fn buffer_size(width: u32, height: u32, render_scale: f64) -> (u32, u32) {
+    let scale = |size: u32| ((size as f64 * render_scale).round() as u32).max(1);
+    (scale(width), scale(height))
+}
+
+// Left drag orbits (control is kept for picking), right or middle drag pans, the wheel dollies and WASD, Q and E fly, faster
+// with shift. Returns whether the camera moved.
+fn navigate(input: &WinitInputHelper, navigation: &mut Navigation, elapsed: f64) -> bool {
+    let before = *navigation;
+    let (dx, dy) = input.mouse_diff();
+    let (dx, dy) = (dx as f64, dy as f64);
+    if input.mouse_held(0) && !input.held_control() {
+        navigation.orbit(dx * 0.01, dy * 0.01);
+    } else if input.mouse_held(1) || input.mouse_held(2) {
+        navigation.pan(-dx * 0.002, dy * 0.002);
+    }
+    navigation.dolly(input.scroll_diff() as f64 * 0.25);
+    let speed = if input.held_shift() { 8.0 } else { 2.0 } * elapsed;
+    let axis = |positive: VirtualKeyCode, negative: VirtualKeyCode| {
+        (input.key_held(positive) as i32 - input.key_held(negative) as i32) as f64 * speed
+    };
+    navigation.fly(
+        axis(VirtualKeyCode::W, VirtualKeyCode::S),
+        axis(VirtualKeyCode::D, VirtualKeyCode::A),
+        axis(VirtualKeyCode::E, VirtualKeyCode::Q),
+    );
+    *navigation != before
+}
+
+// Number keys switch between the debug views, in the order of `DebugView::all`.
+fn pick_view(input: &WinitInputHelper) -> Option<DebugView> {
+    let keys = [
+        VirtualKeyCode::Key1,
+        VirtualKeyCode::Key2,
+        VirtualKeyCode::Key3,
+        VirtualKeyCode::Key4,
+        VirtualKeyCode::Key5,
+        VirtualKeyCode::Key6,
+        VirtualKeyCode::Key7,
+        VirtualKeyCode::Key8,
+    ];
+    keys.iter()
+        .zip(DebugView::all())
+        .find(|(key, _)| input.key_pressed(**key))
+        .map(|(_, view)| view)
+}
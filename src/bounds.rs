@@ -0,0 +1,95 @@
+use crate::matrix::*;
+use crate::point::*;
+
+// Axis aligned box, planes and other unbounded shapes reach infinity on some axes.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Bounds {
+    pub min: Point,
+    pub max: Point,
+}
+
+impl Bounds {
+    pub fn new(min: Point, max: Point) -> Self {
+        Bounds { min, max }
+    }
+
+    pub fn infinite() -> Self {
+        Bounds {
+            min: Point {
+                x: f64::NEG_INFINITY,
+                y: f64::NEG_INFINITY,
+                z: f64::NEG_INFINITY,
+            },
+            max: Point {
+                x: f64::INFINITY,
+                y: f64::INFINITY,
+                z: f64::INFINITY,
+            },
+        }
+    }
+
+    pub fn is_finite(&self) -> bool {
+        [
+            self.min.x, self.min.y, self.min.z, self.max.x, self.max.y, self.max.z,
+        ]
+        .iter()
+        .all(|c| c.is_finite())
+    }
+
+    pub fn union(&self, other: &Bounds) -> Bounds {
+        Bounds {
+            min: Point {
+                x: self.min.x.min(other.min.x),
+                y: self.min.y.min(other.min.y),
+                z: self.min.z.min(other.min.z),
+            },
+            max: Point {
+                x: self.max.x.max(other.max.x),
+                y: self.max.y.max(other.max.y),
+                z: self.max.z.max(other.max.z),
+            },
+        }
+    }
+
+    // Box around the transformed corners. Rotating an infinite box could spread it over any
+    // axis, it stays infinite on all of them.
+    pub fn transform(&self, transform: &Matrix44) -> Bounds {
+        if !self.is_finite() {
+            return Bounds::infinite();
+        }
+        let corners = self.corners().map(|corner| *transform * corner);
+        corners
+            .iter()
+            .fold(Bounds::new(corners[0], corners[0]), |bounds, corner| {
+                bounds.union(&Bounds::new(*corner, *corner))
+            })
+    }
+
+    pub fn corners(&self) -> [Point; 8] {
+        let mut corners = [self.min; 8];
+        for (i, corner) in corners.iter_mut().enumerate() {
+            if i & 1 == 1 {
+                corner.x = self.max.x;
+            }
+            if i & 2 == 2 {
+                corner.y = self.max.y;
+            }
+            if i & 4 == 4 {
+                corner.z = self.max.z;
+            }
+        }
+        corners
+    }
+
+    // Grown by `margin` on every side.
+    pub fn padded(&self, margin: f64) -> Bounds {
+        Bounds {
+            min: self.min + -margin,
+            max: self.max + margin,
+        }
+    }
+}
+
+#[cfg(test)]
+#[path = "./bounds_tests.rs"]
+mod bounds_tests;
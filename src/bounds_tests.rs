@@ -0,0 +1,57 @@
+#[cfg(test)]
+mod tests {
+    use crate::bounds::*;
+
+    fn unit() -> Bounds {
+        Bounds::new(
+            Point {
+                x: -1.0,
+                y: -1.0,
+                z: -1.0,
+            },
+            Point {
+                x: 1.0,
+                y: 1.0,
+                z: 1.0,
+            },
+        )
+    }
+
+    #[test]
+    fn test_transform() {
+        let bounds = unit().transform(&Matrix44::scaling(2.0, 1.0, 1.0).translate(1.0, 0.0, 0.0));
+        assert_eq!(
+            bounds,
+            Bounds::new(
+                Point {
+                    x: -1.0,
+                    y: -1.0,
+                    z: -1.0,
+                },
+                Point {
+                    x: 3.0,
+                    y: 1.0,
+                    z: 1.0,
+                },
+            )
+        );
+    }
+
+    #[test]
+    fn test_rotation_grows_the_box() {
+        let bounds = unit().transform(&Matrix44::rotation_y(std::f64::consts::FRAC_PI_4));
+        let half = 2.0_f64.sqrt();
+        assert!((bounds.max.x - half).abs() < 1e-9);
+        assert!((bounds.min.z + half).abs() < 1e-9);
+        assert!((bounds.max.y - 1.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_infinite() {
+        assert!(unit().is_finite());
+        assert!(!Bounds::infinite().is_finite());
+        let bounds = Bounds::infinite().transform(&Matrix44::translation(1.0, 2.0, 3.0));
+        assert!(!bounds.is_finite());
+        assert!(!unit().union(&Bounds::infinite()).is_finite());
+    }
+}
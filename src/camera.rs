@@ -1,10 +1,19 @@
 use crate::matrix::*;
+use crate::motion::*;
 use crate::point::*;
 use crate::ray::*;
+use crate::vector3::*;
 
+#[derive(Clone, Debug)]
 pub struct Camera {
+    pub hsize: u32,
+    pub vsize: u32,
+    pub field_of_view: f64,
     pixel_size: f64,
-    pub transform: Matrix44,
+    transform: Matrix44,
+    // Kept up to date by `set_transform`, takes camera space to world space.
+    inverse: Matrix44,
+    pub motion: Option<Motion>,
     half_width: f64,
     half_height: f64,
 }
@@ -24,32 +33,129 @@ impl Camera {
         }
         let pixel_size = (half_width * 2.0) / hsize as f64;
         Camera {
+            hsize,
+            vsize,
+            field_of_view,
             pixel_size,
             half_width,
             half_height,
             transform: Matrix44::identity(),
+            inverse: Matrix44::identity(),
+            motion: None,
+        }
+    }
+
+    // Square camera 5 units down -z looking at the origin, the view most tests render.
+    #[cfg(test)]
+    pub fn test_camera(size: u32, field_of_view: f64) -> Camera {
+        let mut cam = Camera::new(size, size, field_of_view);
+        cam.set_transform(view_transform(
+            Point {
+                x: 0.0,
+                y: 0.0,
+                z: -5.0,
+            },
+            Point::zero(),
+            Vector3 {
+                x: 0.0,
+                y: 1.0,
+                z: 0.0,
+            },
+        ));
+        cam
+    }
+
+    // Same view at another resolution, the field of view still spans the longer side.
+    pub fn resized(&self, hsize: u32, vsize: u32) -> Camera {
+        Camera {
+            transform: self.transform,
+            inverse: self.inverse,
+            motion: self.motion,
+            ..Camera::new(hsize, vsize, self.field_of_view)
         }
     }
 
     pub fn ray_for_pixel(&self, x: u32, y: u32) -> Ray {
-        let xoffset = (x as f64 + 0.5) * self.pixel_size;
-        let yoffset = (y as f64 + 0.5) * self.pixel_size;
+        self.ray_for_pixel_at(x, y, 0.0)
+    }
+
+    // Ray through the center of a pixel, emitted at the given shutter time.
+    pub fn ray_for_pixel_at(&self, x: u32, y: u32, time: f64) -> Ray {
+        self.ray_for_subpixel(x as f64 + 0.5, y as f64 + 0.5, time)
+    }
+
+    // Ray through any point of the image plane, in pixels from the top left corner of the image.
+    pub fn ray_for_subpixel(&self, x: f64, y: f64, time: f64) -> Ray {
+        let xoffset = x * self.pixel_size;
+        let yoffset = y * self.pixel_size;
         let world_x = self.half_width - xoffset;
         let world_y = self.half_height - yoffset;
-        let pixel = self.transform.invert()
+        // Only a moving camera needs a new inverse for every ray.
+        let inverse = match self.motion {
+            Some(_) => self.transform_at(time).invert(),
+            None => self.inverse,
+        };
+        let pixel = inverse
             * Point {
                 x: world_x,
                 y: world_y,
                 z: -1.0,
             };
-        let origin = self.transform.invert()
+        let origin = inverse
             * Point {
                 x: 0.0,
                 y: 0.0,
                 z: 0.0,
             };
         let direction = (pixel - origin).normalize();
-        Ray { origin, direction }
+        Ray {
+            origin,
+            direction,
+            time,
+        }
+    }
+
+    pub fn transform(&self) -> &Matrix44 {
+        &self.transform
+    }
+
+    pub fn set_transform(&mut self, transform: Matrix44) {
+        self.transform = transform;
+        self.inverse = transform.invert();
+    }
+
+    pub fn transform_at(&self, time: f64) -> Matrix44 {
+        match &self.motion {
+            Some(motion) => motion.transform_at(&self.transform, time),
+            None => self.transform,
+        }
+    }
+
+    // Position of the eye in world space.
+    pub fn position(&self) -> Point {
+        self.inverse * Point::zero()
+    }
+
+    // Direction the camera is looking at, in world space.
+    pub fn forward(&self) -> Vector3 {
+        (self.inverse
+            * Vector3 {
+                x: 0.0,
+                y: 0.0,
+                z: -1.0,
+            })
+        .normalize()
+    }
+
+    // Up vector of the camera, in world space.
+    pub fn up(&self) -> Vector3 {
+        (self.inverse
+            * Vector3 {
+                x: 0.0,
+                y: 1.0,
+                z: 0.0,
+            })
+        .normalize()
     }
 }
 
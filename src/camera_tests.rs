@@ -3,7 +3,6 @@ mod tests {
     use crate::camera::*;
     use crate::color::*;
     use crate::intersection::*;
-    use crate::vector3::*;
     use crate::world::*;
 
     #[test]
@@ -18,6 +17,18 @@ mod tests {
         assert_eq!(c.pixel_size, 0.009999999999999998);
     }
 
+    #[test]
+    fn test_resized_keeps_view() {
+        let mut c = Camera::new(200, 125, std::f64::consts::PI / 2.0);
+        c.set_transform(crate::matrix::Matrix44::translation(0.0, -2.0, 5.0));
+        let r = c.resized(125, 200);
+        assert_eq!((r.hsize, r.vsize), (125, 200));
+        assert_eq!(r.pixel_size, 0.009999999999999998);
+        assert_eq!(r.transform, c.transform);
+        assert_eq!(r.position(), c.position());
+        assert_eq!(r.field_of_view, c.field_of_view);
+    }
+
     #[test]
     fn test_ray_through_canvas_center() {
         let c = Camera::new(201, 101, std::f64::consts::PI / 2.0);
@@ -36,7 +47,9 @@ mod tests {
     #[test]
     fn test_ray_camera_transformed() {
         let mut c = Camera::new(201, 101, std::f64::consts::FRAC_PI_2);
-        c.transform = Matrix44::translation(0.0, -2.0, 5.0).rotate_y(std::f64::consts::FRAC_PI_4);
+        c.set_transform(
+            Matrix44::translation(0.0, -2.0, 5.0).rotate_y(std::f64::consts::FRAC_PI_4),
+        );
         let r = c.ray_for_pixel(100, 50);
         assert_eq!(
             r.origin,
@@ -74,20 +87,7 @@ mod tests {
     #[test]
     fn test_render_pixel() {
         let world = World::default();
-        let mut camera = Camera::new(11, 11, std::f64::consts::FRAC_PI_2);
-        camera.transform = view_transform(
-            Point {
-                x: 0.0,
-                y: 0.0,
-                z: -5.0,
-            },
-            Point::zero(),
-            Vector3 {
-                x: 0.0,
-                y: 1.0,
-                z: 0.0,
-            },
-        );
+        let camera = Camera::test_camera(11, std::f64::consts::FRAC_PI_2);
         let ray = camera.ray_for_pixel(5, 5);
         let color = color_at(&world, &ray, 0);
         assert_eq!(
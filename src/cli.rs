@@ -0,0 +1,86 @@
+use crate::camera::*;
+use crate::debug_view::*;
+use crate::demo::*;
+use crate::denoise::*;
+use crate::render::*;
+use crate::sampler::*;
+use crate::scene::*;
+use crate::tonemap::*;
+use crate::world::*;
+
+// Command line handling shared by the exporter and the viewer.
+
+const MAX_RECURSION: i32 = 5;
+
+// `--scene file.scene` renders a scene file instead of the built in scene.
+pub fn scene_from_args(args: &[String]) -> (Camera, World) {
+    match arg_value(args, "--scene") {
+        Some(path) => load_scene(path, WIDTH, HEIGHT).unwrap_or_else(|e| panic!("{}: {}", path, e)),
+        None => get_scene(),
+    }
+}
+
+// Reads `--samples 16`, `--sampler sobol`, `--seed 7`, `--shutter 0.0:0.5`, `--exposure 1.5`,
+// `--tonemap aces`, `--dither`, `--denoise [passes]` and `--view normals` style flags.
+pub fn render_settings(args: &[String]) -> RenderSettings {
+    let mut settings = RenderSettings {
+        max_recursion: MAX_RECURSION,
+        ..RenderSettings::default()
+    };
+    if let Some(samples) = arg_value(args, "--samples") {
+        settings.samples = samples.parse().expect("--samples expects a number");
+    }
+    if let Some(sampler) = arg_value(args, "--sampler") {
+        settings.sampler.kind = SamplerKind::from_name(sampler).unwrap_or_else(|| {
+            let names: Vec<&str> = SamplerKind::all().iter().map(|k| k.name()).collect();
+            panic!("--sampler expects one of {}", names.join(", "))
+        });
+    }
+    if let Some(seed) = arg_value(args, "--seed") {
+        settings.sampler.seed = seed.parse().expect("--seed expects a number");
+    }
+    if let Some((open, close)) = arg_value(args, "--shutter").and_then(|v| v.split_once(':')) {
+        settings.shutter_open = open.parse().expect("--shutter expects open:close");
+        settings.shutter_close = close.parse().expect("--shutter expects open:close");
+    }
+    if let Some(exposure) = arg_value(args, "--exposure") {
+        settings.post_process.exposure = exposure.parse().expect("--exposure expects a number");
+    }
+    if let Some(tone_mapper) = arg_value(args, "--tonemap") {
+        settings.post_process.tone_mapper = match tone_mapper.split_once(':') {
+            Some(("reinhard-extended", white)) => ToneMapper::ExtendedReinhard {
+                white: white
+                    .parse()
+                    .expect("reinhard-extended expects a white point"),
+            },
+            None if tone_mapper == "reinhard" => ToneMapper::Reinhard,
+            None if tone_mapper == "aces" => ToneMapper::AcesFilmic,
+            None if tone_mapper == "clamp" => ToneMapper::Clamp,
+            _ => panic!("--tonemap expects clamp, reinhard, reinhard-extended:<white> or aces"),
+        };
+    }
+    settings.post_process.dither = args.iter().any(|a| a == "--dither");
+    if args.iter().any(|a| a == "--denoise") {
+        let mut denoiser = Denoiser::default();
+        if let Some(iterations) = arg_value(args, "--denoise") {
+            denoiser.iterations = iterations.parse().expect("--denoise expects a pass count");
+        }
+        settings.denoiser = Some(denoiser);
+    }
+    if let Some(view) = arg_value(args, "--view") {
+        settings.view = DebugView::from_name(view).unwrap_or_else(|| {
+            let names: Vec<&str> = DebugView::all().iter().map(|v| v.name()).collect();
+            panic!("--view expects one of {}", names.join(", "))
+        });
+    }
+    settings
+}
+
+// Returns the value following a flag, e.g. `--iod 0.065`
+pub fn arg_value<'a>(args: &'a [String], flag: &str) -> Option<&'a str> {
+    args.iter()
+        .position(|a| a == flag)
+        .and_then(|i| args.get(i + 1))
+        .filter(|v| !v.starts_with("--"))
+        .map(|v| v.as_str())
+}
@@ -1,4 +1,8 @@
 use std::ops::{Add, Mul, Sub};
+
+// Colors are linear, lighting and blending all happen in linear space. Values coming from or going
+// to the outside world (8 bit images, hex codes, color pickers) are sRGB encoded and go through the
+// conversions below.
 #[derive(Copy, Clone, Debug, PartialEq)]
 pub struct Color {
     pub red: f32,
@@ -86,6 +90,132 @@ impl Color {
             blue: 0.0,
         }
     }
+
+    // Decodes a color whose channels are sRGB encoded, e.g. picked on screen, into linear space.
+    pub fn to_linear(self) -> Color {
+        self.map(srgb_to_linear)
+    }
+
+    // sRGB encoded copy of a linear color, ready to be quantized for display.
+    pub fn to_srgb(self) -> Color {
+        self.map(linear_to_srgb)
+    }
+
+    // Color from 8 bit sRGB channels, as stored in PNG files.
+    pub fn from_srgb_u8(red: u8, green: u8, blue: u8) -> Color {
+        Color {
+            red: red as f32 / 255.0,
+            green: green as f32 / 255.0,
+            blue: blue as f32 / 255.0,
+        }
+        .to_linear()
+    }
+
+    // 8 bit sRGB channels, clamped to [0.0, 1.0] first.
+    pub fn to_srgb_u8(self) -> [u8; 3] {
+        let c = self.to_srgb();
+        [quantize(c.red), quantize(c.green), quantize(c.blue)]
+    }
+
+    // Parses `#rrggbb` or `#rgb`, the leading `#` is optional. Hex codes are sRGB encoded.
+    pub fn from_hex(hex: &str) -> Option<Color> {
+        let hex = hex.strip_prefix('#').unwrap_or(hex);
+        if !hex.is_ascii() {
+            return None;
+        }
+        let channel = |digits: &str| u8::from_str_radix(digits, 16).ok();
+        match hex.len() {
+            6 => Some(Color::from_srgb_u8(
+                channel(&hex[0..2])?,
+                channel(&hex[2..4])?,
+                channel(&hex[4..6])?,
+            )),
+            3 => Some(Color::from_srgb_u8(
+                channel(&hex[0..1])? * 17,
+                channel(&hex[1..2])? * 17,
+                channel(&hex[2..3])? * 17,
+            )),
+            _ => None,
+        }
+    }
+
+    // `#rrggbb` sRGB code of the color.
+    pub fn to_hex(self) -> String {
+        let [red, green, blue] = self.to_srgb_u8();
+        format!("#{:02x}{:02x}{:02x}", red, green, blue)
+    }
+
+    // Hue in degrees, saturation and value in [0.0, 1.0]. Like color pickers, HSV describes the sRGB
+    // encoded color, the result is decoded to linear.
+    pub fn from_hsv(hue: f32, saturation: f32, value: f32) -> Color {
+        let h = hue.rem_euclid(360.0) / 60.0;
+        let chroma = value * saturation;
+        let x = chroma * (1.0 - (h % 2.0 - 1.0).abs());
+        let (red, green, blue) = match h as u32 {
+            0 => (chroma, x, 0.0),
+            1 => (x, chroma, 0.0),
+            2 => (0.0, chroma, x),
+            3 => (0.0, x, chroma),
+            4 => (x, 0.0, chroma),
+            _ => (chroma, 0.0, x),
+        };
+        let m = value - chroma;
+        Color {
+            red: red + m,
+            green: green + m,
+            blue: blue + m,
+        }
+        .to_linear()
+    }
+
+    // (hue in degrees, saturation, value) of the sRGB encoded color, inverse of `from_hsv`.
+    pub fn to_hsv(self) -> (f32, f32, f32) {
+        let c = self.to_srgb();
+        let max = c.red.max(c.green).max(c.blue);
+        let min = c.red.min(c.green).min(c.blue);
+        let chroma = max - min;
+        let hue = if chroma == 0.0 {
+            0.0
+        } else if max == c.red {
+            60.0 * ((c.green - c.blue) / chroma).rem_euclid(6.0)
+        } else if max == c.green {
+            60.0 * ((c.blue - c.red) / chroma + 2.0)
+        } else {
+            60.0 * ((c.red - c.green) / chroma + 4.0)
+        };
+        let saturation = if max == 0.0 { 0.0 } else { chroma / max };
+        (hue, saturation, max)
+    }
+
+    fn map(self, f: fn(f32) -> f32) -> Color {
+        Color {
+            red: f(self.red),
+            green: f(self.green),
+            blue: f(self.blue),
+        }
+    }
+}
+
+// sRGB transfer function, channel by channel.
+pub fn srgb_to_linear(channel: f32) -> f32 {
+    if channel <= 0.04045 {
+        channel / 12.92
+    } else {
+        ((channel + 0.055) / 1.055).powf(2.4)
+    }
+}
+
+pub fn linear_to_srgb(channel: f32) -> f32 {
+    if channel <= 0.0031308 {
+        channel * 12.92
+    } else {
+        1.055 * channel.powf(1.0 / 2.4) - 0.055
+    }
+}
+
+// Encoded channel to 8 bits, clamped to [0.0, 1.0].
+pub fn quantize(channel: f32) -> u8 {
+    (channel.clamp(0.0, 1.0) * 255.0).round() as u8
 }
 
 impl Default for Color {
@@ -173,3 +303,7 @@ impl Sub<Color> for Color {
         }
     }
 }
+
+#[cfg(test)]
+#[path = "./color_tests.rs"]
+mod color_tests;
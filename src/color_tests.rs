@@ -0,0 +1,94 @@
+#[cfg(test)]
+mod tests {
+    use crate::color::*;
+
+    fn assert_close(a: Color, b: Color) {
+        assert!(
+            (a.red - b.red).abs() < 1e-4
+                && (a.green - b.green).abs() < 1e-4
+                && (a.blue - b.blue).abs() < 1e-4,
+            "{:?} != {:?}",
+            a,
+            b
+        );
+    }
+
+    #[test]
+    fn test_srgb_transfer() {
+        assert_eq!(srgb_to_linear(0.0), 0.0);
+        assert!((linear_to_srgb(1.0) - 1.0).abs() < 1e-6);
+        assert!((srgb_to_linear(0.5) - 0.21404).abs() < 1e-4);
+        assert!((linear_to_srgb(0.5) - 0.73536).abs() < 1e-4);
+        // Linear segment near black.
+        assert!((srgb_to_linear(0.02) - 0.02 / 12.92).abs() < 1e-7);
+        for i in 0..=20 {
+            let c = i as f32 / 20.0;
+            assert!((linear_to_srgb(srgb_to_linear(c)) - c).abs() < 1e-5);
+        }
+    }
+
+    #[test]
+    fn test_srgb_u8_round_trip() {
+        for value in 0..=255 {
+            let color = Color::from_srgb_u8(value, 255 - value, value / 2);
+            assert_eq!(color.to_srgb_u8(), [value, 255 - value, value / 2]);
+        }
+        assert_eq!((Color::white() * 3.0).to_srgb_u8(), [255, 255, 255]);
+        assert_eq!((Color::black() - 1.0).to_srgb_u8(), [0, 0, 0]);
+    }
+
+    #[test]
+    fn test_hex() {
+        assert_eq!(Color::from_hex("#ffffff"), Some(Color::white()));
+        assert_eq!(Color::from_hex("000"), Some(Color::black()));
+        assert_eq!(
+            Color::from_hex("#ff8000"),
+            Some(Color::from_srgb_u8(255, 128, 0))
+        );
+        assert_eq!(Color::from_hex("#f80"), Color::from_hex("#ff8800"));
+        assert_eq!(Color::from_hex("#ff80"), None);
+        assert_eq!(Color::from_hex("#gg0000"), None);
+        assert_eq!(Color::from_hex("#ffé0"), None);
+        assert_eq!(Color::from_srgb_u8(18, 52, 171).to_hex(), "#1234ab");
+        // Mid gray in linear space is well above 0x80 once encoded.
+        assert_eq!((Color::white() * 0.5).to_hex(), "#bcbcbc");
+    }
+
+    #[test]
+    fn test_hsv() {
+        assert_eq!(Color::from_hsv(0.0, 1.0, 1.0), Color::red());
+        assert_eq!(Color::from_hsv(120.0, 1.0, 1.0), Color::green());
+        assert_eq!(Color::from_hsv(180.0, 1.0, 1.0), Color::cyan());
+        assert_eq!(Color::from_hsv(-60.0, 1.0, 1.0), Color::purple());
+        assert_eq!(
+            Color::from_hsv(42.0, 0.0, 0.5),
+            Color::white() * srgb_to_linear(0.5)
+        );
+        let (h, s, v) = Color::red().to_hsv();
+        assert_eq!((h, s), (0.0, 1.0));
+        assert!((v - 1.0).abs() < 1e-6);
+        assert_eq!(Color::black().to_hsv(), (0.0, 0.0, 0.0));
+        let (h, s, v) = Color::from_hex("#3366cc").unwrap().to_hsv();
+        assert!((h - 220.0).abs() < 1e-3);
+        assert!((s - 0.75).abs() < 1e-3);
+        assert!((v - 0.8).abs() < 1e-3);
+        for hue in [10.0, 75.0, 150.0, 200.0, 290.0, 345.0] {
+            let color = Color::from_hsv(hue, 0.6, 0.7);
+            let (h, s, v) = color.to_hsv();
+            assert_close(Color::from_hsv(h, s, v), color);
+            assert!((h - hue).abs() < 1e-2);
+        }
+    }
+
+    #[test]
+    fn test_to_linear_to_srgb() {
+        let color = Color {
+            red: 0.2,
+            green: 0.5,
+            blue: 0.9,
+        };
+        assert_close(color.to_srgb().to_linear(), color);
+        assert_eq!(Color::white().to_linear(), Color::white());
+        assert_eq!(Color::black().to_srgb(), Color::black());
+    }
+}
@@ -0,0 +1,173 @@
+use crate::aov::*;
+use crate::camera::*;
+use crate::color::*;
+use crate::intersection::*;
+use crate::light::*;
+use crate::ray::*;
+use crate::stats::{count_intersection_tests, intersection_tests};
+use crate::world::*;
+
+// What the render shows. Anything but the beauty image is a false color picture of one of the
+// quantities that go into shading, meant to be looked at as is, without exposure or tone mapping.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum DebugView {
+    Beauty,
+    // World normals remapped from [-1, 1] to [0, 1].
+    Normals,
+    // Camera space depth, white up close and fading with the distance.
+    Depth,
+    // Texture coordinates in red and green.
+    Uv,
+    ObjectId,
+    // White where every light reaches the surface, black where all of them are blocked.
+    ShadowMask,
+    // Intersection tests for the whole pixel, shadow and secondary rays included, as a heat map.
+    IntersectionTests,
+    // Deepest reflection or refraction bounce, as a heat map up to the recursion limit.
+    RecursionDepth,
+}
+
+// Background of the views that would show black for an actual value.
+const MISS: Color = Color {
+    red: 0.05,
+    green: 0.05,
+    blue: 0.15,
+};
+
+impl DebugView {
+    pub fn all() -> [DebugView; 8] {
+        [
+            DebugView::Beauty,
+            DebugView::Normals,
+            DebugView::Depth,
+            DebugView::Uv,
+            DebugView::ObjectId,
+            DebugView::ShadowMask,
+            DebugView::IntersectionTests,
+            DebugView::RecursionDepth,
+        ]
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            DebugView::Beauty => "beauty",
+            DebugView::Normals => "normals",
+            DebugView::Depth => "depth",
+            DebugView::Uv => "uv",
+            DebugView::ObjectId => "object_id",
+            DebugView::ShadowMask => "shadow",
+            DebugView::IntersectionTests => "tests",
+            DebugView::RecursionDepth => "recursion",
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<DebugView> {
+        DebugView::all()
+            .into_iter()
+            .find(|view| view.name() == name)
+    }
+
+    // Color of a camera ray in this view, `remaining` is the recursion budget of the render.
+    pub fn color(&self, cam: &Camera, world: &World, ray: &Ray, remaining: i32) -> Color {
+        if *self == DebugView::Beauty {
+            return color_at(world, ray, remaining);
+        }
+        if *self == DebugView::IntersectionTests {
+            count_intersection_tests();
+            let before = intersection_tests();
+            color_at(world, ray, remaining);
+            let tests = (intersection_tests() - before) as f32;
+            // A pixel testing every object once per level of recursion is already hot.
+            let budget = (world.objects.len().max(1) * (remaining.max(0) as usize + 1)) as f32;
+            return heat(tests / (2.0 * budget));
+        }
+        let (index, comps) = match hit_indexed(ray, world) {
+            Some(hit) => hit,
+            None if *self == DebugView::RecursionDepth => return heat(0.0),
+            None if *self == DebugView::ObjectId => return Color::black(),
+            None => return MISS,
+        };
+        match self {
+            DebugView::Normals => {
+                let n = comps.normalv;
+                (Color {
+                    red: n.x as f32,
+                    green: n.y as f32,
+                    blue: n.z as f32,
+                } * 0.5
+                    + 0.5)
+                    .to_linear()
+            }
+            DebugView::Depth => {
+                let depth = -(cam.transform_at(ray.time) * comps.point).z;
+                (Color::white() * (1.0 / (1.0 + depth as f32 / 10.0))).to_linear()
+            }
+            DebugView::Uv => {
+                let (u, v) = comps.object.uv_at(&comps.point);
+                Color {
+                    red: u as f32,
+                    green: v as f32,
+                    blue: 0.0,
+                }
+                .to_linear()
+            }
+            DebugView::ObjectId => id_color(index as u32 + 1),
+            DebugView::ShadowMask => {
+                let lit = world
+                    .lights
+                    .iter()
+                    .filter(|light| !is_shadowed(world, &comps.over_point, light, comps.time))
+                    .count();
+                (Color::white() * (lit as f32 / world.lights.len().max(1) as f32)).to_linear()
+            }
+            DebugView::RecursionDepth => {
+                let depth = 1 + bounces(world, &comps, remaining);
+                heat(depth as f32 / (remaining.max(0) + 1) as f32)
+            }
+            DebugView::Beauty | DebugView::IntersectionTests => unreachable!(),
+        }
+    }
+}
+
+// Number of reflection and refraction levels below a hit, following the same rules as shading.
+fn bounces(world: &World, comps: &Computations, remaining: i32) -> i32 {
+    if remaining <= 0 {
+        return 0;
+    }
+    let material = comps.object.material();
+    let mut rays = vec![];
+    if material.reflective > 0.0 {
+        rays.push(reflected_ray(comps));
+    }
+    if material.transparency > 0.0 {
+        rays.extend(refracted_ray(comps));
+    }
+    rays.iter()
+        .map(|ray| {
+            let intersections = intersect_world(ray, world);
+            match hit(intersections.to_vec()) {
+                Some(next) => {
+                    let next = prepare_computations(&next, ray, &intersections);
+                    1 + bounces(world, &next, remaining - 1)
+                }
+                None => 0,
+            }
+        })
+        .max()
+        .unwrap_or(0)
+}
+
+// Black, red, yellow then white as `t` goes from 0.0 to 1.0.
+pub fn heat(t: f32) -> Color {
+    let t = t.clamp(0.0, 1.0) * 3.0;
+    Color {
+        red: t.min(1.0),
+        green: (t - 1.0).clamp(0.0, 1.0),
+        blue: (t - 2.0).clamp(0.0, 1.0),
+    }
+    .to_linear()
+}
+
+#[cfg(test)]
+#[path = "./debug_view_tests.rs"]
+mod debug_view_tests;
@@ -0,0 +1,159 @@
+#[cfg(test)]
+mod tests {
+    use crate::debug_view::*;
+    use crate::material::*;
+    use crate::matrix::*;
+    use crate::objects::plane::*;
+    use crate::objects::sphere::*;
+    use crate::objects::*;
+    use crate::point::*;
+    use crate::render::*;
+    use crate::tonemap::*;
+    use std::sync::Arc;
+
+    #[test]
+    fn test_names() {
+        for view in DebugView::all() {
+            assert_eq!(DebugView::from_name(view.name()), Some(view));
+        }
+        assert_eq!(DebugView::from_name("albedo"), None);
+    }
+
+    #[test]
+    fn test_beauty_is_the_render() {
+        let cam = Camera::test_camera(11, std::f64::consts::FRAC_PI_2);
+        let world = World::default();
+        let ray = cam.ray_for_pixel(5, 5);
+        assert_eq!(
+            DebugView::Beauty.color(&cam, &world, &ray, 5),
+            color_at(&world, &ray, 5)
+        );
+    }
+
+    #[test]
+    fn test_normals_and_depth() {
+        let cam = Camera::test_camera(11, std::f64::consts::FRAC_PI_2);
+        let world = World::default();
+        let ray = cam.ray_for_pixel(5, 5);
+        // The sphere faces the camera, its normal points straight back at it.
+        assert_eq!(
+            DebugView::Normals.color(&cam, &world, &ray, 5),
+            Color {
+                red: 0.5,
+                green: 0.5,
+                blue: 0.0,
+            }
+            .to_linear()
+        );
+        let near = DebugView::Depth.color(&cam, &world, &ray, 5);
+        let far = DebugView::Depth.color(&cam, &world, &cam.ray_for_pixel(3, 5), 5);
+        assert!(near.red > far.red);
+    }
+
+    #[test]
+    fn test_uv() {
+        let plane = Object::new(Plane, Material::default());
+        assert_eq!(
+            plane.uv_at(&Point {
+                x: 0.25,
+                y: 0.0,
+                z: -0.5,
+            }),
+            (0.25, 0.5)
+        );
+        let sphere = Object::new(Sphere, Material::default());
+        let (u, v) = sphere.uv_at(&Point {
+            x: 0.0,
+            y: 1.0,
+            z: 0.0,
+        });
+        assert!((0.0..=1.0).contains(&u));
+        assert!((v - 1.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_shadow_mask() {
+        let cam = Camera::test_camera(11, std::f64::consts::FRAC_PI_2);
+        let world = World::new(
+            vec![
+                Object::new(Plane, Material::default()).set_transform(
+                    Matrix44::rotation_x(std::f64::consts::FRAC_PI_2).translate(0.0, 0.0, 1.0),
+                ),
+                Object::new(Sphere, Material::default()),
+            ],
+            World::default().lights,
+        );
+        let lit = DebugView::ShadowMask.color(&cam, &world, &cam.ray_for_pixel(0, 0), 5);
+        assert_eq!(lit, Color::white());
+        // The light, the center of the sphere and this point of the wall are lined up.
+        let origin = Point {
+            x: 0.0,
+            y: 0.0,
+            z: -5.0,
+        };
+        let target = Point {
+            x: 1.0,
+            y: -1.0,
+            z: 1.0,
+        };
+        let ray = Ray {
+            origin,
+            direction: (target - origin).normalize(),
+            time: 0.0,
+        };
+        let shadowed = DebugView::ShadowMask.color(&cam, &world, &ray, 5);
+        assert_eq!(shadowed, Color::black());
+    }
+
+    #[test]
+    fn test_intersection_tests_counts_secondary_rays() {
+        let cam = Camera::test_camera(11, std::f64::consts::FRAC_PI_2);
+        let mut world = World::default();
+        let ray = cam.ray_for_pixel(5, 5);
+        let matte = DebugView::IntersectionTests.color(&cam, &world, &ray, 5);
+        let mut material = world.objects[0].material().clone();
+        material.reflective = 0.5;
+        Arc::make_mut(&mut world.objects[0]).set_material(material);
+        let shiny = DebugView::IntersectionTests.color(&cam, &world, &ray, 5);
+        assert!(shiny.red + shiny.green + shiny.blue > matte.red + matte.green + matte.blue);
+    }
+
+    #[test]
+    fn test_recursion_depth() {
+        let cam = Camera::test_camera(11, std::f64::consts::FRAC_PI_2);
+        let mut world = World::default();
+        let ray = cam.ray_for_pixel(5, 5);
+        let miss = cam.ray_for_pixel(0, 0);
+        assert_eq!(
+            DebugView::RecursionDepth.color(&cam, &world, &miss, 5),
+            Color::black()
+        );
+        assert_eq!(
+            DebugView::RecursionDepth.color(&cam, &world, &ray, 5),
+            heat(1.0 / 6.0)
+        );
+        let mut material = world.objects[0].material().clone();
+        material.transparency = 1.0;
+        material.refractive_index = 1.5;
+        Arc::make_mut(&mut world.objects[0]).set_material(material);
+        // The ray goes through the outer sphere and hits the inner one.
+        assert_eq!(
+            DebugView::RecursionDepth.color(&cam, &world, &ray, 5),
+            heat(2.0 / 6.0)
+        );
+    }
+
+    #[test]
+    fn test_settings_select_view() {
+        let cam = Camera::test_camera(11, std::f64::consts::FRAC_PI_2);
+        let world = World::default();
+        let settings = RenderSettings {
+            view: DebugView::ObjectId,
+            ..RenderSettings::default()
+        };
+        let framebuffer = render(&cam, &world, &settings);
+        assert_eq!(framebuffer.get(0, 0), Color::black());
+        assert_eq!(framebuffer.get(5, 5), id_color(1));
+        assert_eq!(settings.display(), PostProcess::default());
+    }
+}
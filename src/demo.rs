@@ -0,0 +1,305 @@
+use crate::animation::*;
+use crate::camera::*;
+use crate::color::*;
+use crate::light::*;
+use crate::material::*;
+use crate::matrix::*;
+use crate::objects::cube::*;
+use crate::objects::plane::*;
+use crate::objects::sphere::*;
+use crate::objects::*;
+use crate::patterns::blend::*;
+use crate::patterns::checker::*;
+use crate::patterns::perturbed::*;
+use crate::patterns::radial_gradient::*;
+use crate::patterns::ring::*;
+use crate::patterns::solid_color::*;
+use crate::patterns::stripe::*;
+use crate::patterns::*;
+use crate::point::*;
+use crate::vector3::*;
+use crate::world::*;
+
+// Built in scene and animation, rendered when no scene file is given.
+pub const WIDTH: u32 = 400;
+pub const HEIGHT: u32 = 400;
+// pub const WIDTH: u32 = 1200;
+// pub const HEIGHT: u32 = 800;
+// pub const WIDTH: u32 = 2560;
+// pub const HEIGHT: u32 = 1440;
+
+// Scene colors are picked in sRGB, `to_linear` brings them into the linear working space.
+pub fn get_scene() -> (Camera, World) {
+    let middlesphere = Object::new(
+        Sphere,
+        Material {
+            color: Color {
+                red: 0.1,
+                green: 1.0,
+                blue: 0.5,
+            }
+            .to_linear(),
+            diffuse: 1.0,
+            specular: 0.0,
+            // refractive_index: 1.59,
+            // transparency: 1.0,
+            pattern: Some(
+                Pattern::new(Checker::new(
+                    Pattern::new(SolidColor::new(Color::cyan().to_linear())),
+                    Pattern::new(SolidColor::new((Color::cyan() - 0.08).to_linear())),
+                ))
+                .set_transform(
+                    Matrix44::scaling(0.5, 0.5, 0.5)
+                        .rotate_z(std::f64::consts::FRAC_PI_6)
+                        .rotate_x(-std::f64::consts::FRAC_PI_6),
+                ),
+            ),
+            ..Material::default()
+        },
+    )
+    .set_transform(Matrix44::scaling(2.0, 2.0, 2.0).translate(-0.5, 2.0, 3.5));
+    let rightsphere = Object::new(
+        Sphere,
+        Material {
+            color: Color {
+                red: 0.5,
+                green: 1.0,
+                blue: 0.1,
+            }
+            .to_linear(),
+            diffuse: 0.7,
+            specular: 0.3,
+            reflective: 0.3,
+            pattern: Some(Pattern::new(Perturbed::new(
+                Pattern::new(Ring::new(
+                    Pattern::new(SolidColor::new((Color::yellow() + 0.2).to_linear())),
+                    Pattern::new(SolidColor::new(Color::orange().to_linear())),
+                ))
+                .set_transform(
+                    Matrix44::rotation_x(std::f64::consts::FRAC_PI_2).scale(0.1, 0.1, 0.1),
+                ),
+                0.4,
+            ))),
+            ..Material::default()
+        },
+    )
+    .set_transform(Matrix44::scaling(0.5, 0.5, 0.5).translate(1.5, 0.5, -0.5));
+    let rightsphere_2 = Object::new(
+        Sphere,
+        Material {
+            color: Color {
+                red: 0.4,
+                green: 0.4,
+                blue: 0.4,
+            }
+            .to_linear(),
+            diffuse: 0.1,
+            refractive_index: 1.59,
+            transparency: 0.9,
+            specular: 1.0,
+            shininess: 300.0,
+            ..Material::default()
+        },
+    )
+    .set_transform(Matrix44::scaling(0.3, 0.3, 0.3).translate(0.0, 0.3, -1.5));
+    let leftsphere = Object::new(
+        Sphere,
+        Material {
+            color: Color {
+                red: 1.0,
+                green: 0.8,
+                blue: 0.1,
+            }
+            .to_linear(),
+            diffuse: 0.7,
+            specular: 0.8,
+            pattern: Some(
+                Pattern::new(RadialGradient::new(
+                    Pattern::new(SolidColor::new((Color::green() + 0.56).to_linear())),
+                    Pattern::new(SolidColor::new((Color::green() + 0.23).to_linear())),
+                ))
+                .set_transform(
+                    Matrix44::rotation_x(-std::f64::consts::FRAC_PI_4).scale(0.2, 0.2, 0.2),
+                ),
+            ),
+            ..Material::default()
+        },
+    )
+    .set_transform(Matrix44::scaling(0.33, 0.33, 0.33).translate(-1.5, 0.33, -0.75));
+    let plane = Object::new(
+        Plane,
+        Material {
+            specular: 0.0,
+            diffuse: 0.5,
+            ambient: 0.01,
+            reflective: 0.05,
+            color: Color {
+                red: 0.0,
+                green: 0.9,
+                blue: 0.9,
+            }
+            .to_linear(),
+            pattern: Some(Pattern::new(Blend::new(
+                Pattern::new(Stripe::new(
+                    Pattern::new(SolidColor::new(Color::gray().to_linear())),
+                    Pattern::new(SolidColor::new((Color::gray() + 0.08).to_linear())),
+                ))
+                .set_transform(
+                    Matrix44::scaling(0.2, 0.2, 0.2).rotate_y(std::f64::consts::FRAC_PI_4),
+                ),
+                Pattern::new(Stripe::new(
+                    Pattern::new(SolidColor::new((Color::braun() + 0.2).to_linear())),
+                    Pattern::new(SolidColor::new((Color::braun() + 0.1).to_linear())),
+                )),
+                0.7,
+            ))),
+            // pattern: Some(Pattern::new(Checker::new(
+            //     Pattern::new(Stripe::new(
+            //         Pattern::new(SolidColor::new(Color::green() - 0.2)),
+            //         Pattern::new(SolidColor::new(Color::green() + 0.08)),
+            //     ))
+            //     .set_transform(Matrix44::scaling(0.2, 0.2, 0.2).rotate_y(std::f64::consts::FRAC_PI_4)),
+            //     Pattern::new(Stripe::new(
+            //         Pattern::new(SolidColor::new(Color::yellow() - 0.4)),
+            //         Pattern::new(SolidColor::new(Color::yellow() - 0.2)),
+            //     ))
+            //     .set_transform(Matrix44::scaling(0.2, 0.2, 0.2).rotate_y(-std::f64::consts::FRAC_PI_4)),
+            // ))),
+            ..Material::default()
+        },
+    );
+    let cube = Object::new(
+        Cube,
+        Material {
+            color: Color {
+                red: 1.0,
+                green: 0.3,
+                blue: 0.5,
+            }
+            .to_linear(),
+            diffuse: 0.7,
+            specular: 0.8,
+            reflective: 0.3,
+            pattern: Some(
+                Pattern::new(Stripe::new(
+                    Pattern::new(SolidColor::new(Color::red().to_linear())),
+                    Pattern::new(SolidColor::new((Color::red() + 0.28).to_linear())),
+                ))
+                .set_transform(
+                    Matrix44::scaling(0.2, 0.2, 0.2).rotate_y(std::f64::consts::FRAC_PI_4),
+                ),
+            ),
+            ..Material::default()
+        },
+    )
+    .set_transform(Matrix44::scaling(0.1, 0.1, 0.1).translate(1.0, 0.1, -2.0));
+
+    let world = World::new(
+        vec![
+            cube,
+            plane,
+            middlesphere,
+            rightsphere,
+            leftsphere,
+            rightsphere_2,
+        ],
+        vec![
+            Light::PointLight(PointLight {
+                position: Point {
+                    x: -10.0,
+                    y: 10.0,
+                    z: -10.0,
+                },
+                color: Color::white(),
+            }),
+            // Light::PointLight(PointLight {
+            //     position: Point {
+            //         x: 10.0,
+            //         y: 3.0,
+            //         z: -30.0,
+            //     },
+            //     color: Color {
+            //         red: 0.7,
+            //         green: 0.7,
+            //         blue: 0.7,
+            //     },
+            // }),
+        ],
+    );
+    let mut cam = Camera::new(WIDTH, HEIGHT, std::f64::consts::PI / 3.0);
+    cam.set_transform(view_transform(
+        Point {
+            x: 0.0,
+            // y: 2.5,
+            // z: -10.0,
+            y: 1.5,
+            z: -5.0,
+        },
+        Point {
+            x: 0.0,
+            y: 1.0,
+            z: 0.0,
+        },
+        Vector3 {
+            x: 0.0,
+            y: 1.0,
+            z: 0.0,
+        },
+    ));
+    return (cam, world);
+}
+
+// Tracks refer to objects and lights by their index in get_scene.
+pub fn get_animation() -> Animation {
+    let up = Vector3 {
+        x: 0.0,
+        y: 1.0,
+        z: 0.0,
+    };
+    let target = Point {
+        x: 0.0,
+        y: 1.0,
+        z: 0.0,
+    };
+    let camera_key = |x: f64, z: f64| CameraKey {
+        from: Point { x, y: 1.5, z },
+        to: target,
+        up,
+        field_of_view: std::f64::consts::PI / 3.0,
+    };
+    let leftsphere_key = |y: f64| TransformKey {
+        translation: Vector3 {
+            x: -1.5,
+            y,
+            z: -0.75,
+        },
+        rotation: Vector3 {
+            x: 0.0,
+            y: 0.0,
+            z: 0.0,
+        },
+        scale: Vector3 {
+            x: 0.33,
+            y: 0.33,
+            z: 0.33,
+        },
+    };
+    Animation {
+        camera: Some(
+            Track::new()
+                .key(0.0, camera_key(-1.5, -4.8), KeyInterpolation::Bezier)
+                .key(1.0, camera_key(0.0, -5.0), KeyInterpolation::Bezier)
+                .key(2.0, camera_key(1.5, -4.8), KeyInterpolation::Bezier),
+        ),
+        objects: vec![(
+            4,
+            Track::new()
+                .key(0.0, leftsphere_key(0.33), KeyInterpolation::Bezier)
+                .key(0.5, leftsphere_key(1.2), KeyInterpolation::Bezier)
+                .key(1.0, leftsphere_key(0.33), KeyInterpolation::Bezier)
+                .key(1.5, leftsphere_key(1.2), KeyInterpolation::Bezier)
+                .key(2.0, leftsphere_key(0.33), KeyInterpolation::Bezier),
+        )],
+        ..Animation::default()
+    }
+}
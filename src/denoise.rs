@@ -0,0 +1,111 @@
+use crate::aov::*;
+use crate::color::*;
+use crate::framebuffer::*;
+
+// Edge avoiding à-trous wavelet filter (Dammertz et al. 2010). Each pass blurs with a 5x5 kernel
+// whose taps are spread twice as far as in the previous pass, neighbours only count when their
+// color, normal, depth and albedo are close to the ones of the pixel being filtered so the blur
+// stops at object boundaries and texture edges.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Denoiser {
+    pub iterations: u32,
+    // Tolerated color difference, halved every pass so later, wider passes only remove fine noise.
+    pub color_sigma: f32,
+    // Tolerated difference between unit normals.
+    pub normal_sigma: f32,
+    // Tolerated depth difference, relative to the depth of the filtered pixel.
+    pub depth_sigma: f32,
+    pub albedo_sigma: f32,
+}
+
+impl Default for Denoiser {
+    fn default() -> Self {
+        Denoiser {
+            iterations: 5,
+            color_sigma: 0.6,
+            normal_sigma: 0.3,
+            depth_sigma: 0.05,
+            albedo_sigma: 0.1,
+        }
+    }
+}
+
+const KERNEL: [f32; 5] = [1.0 / 16.0, 1.0 / 4.0, 3.0 / 8.0, 1.0 / 4.0, 1.0 / 16.0];
+
+impl Denoiser {
+    // Filters a linear image, to run before tone mapping. The guide buffers come from the same render.
+    pub fn denoise(&self, color: &Framebuffer, aovs: &Aovs) -> Framebuffer {
+        let mut current = color.clone();
+        for iteration in 0..self.iterations {
+            let step = 1_i64 << iteration;
+            let color_sigma = self.color_sigma / (1 << iteration) as f32;
+            let mut next = current.clone();
+            for y in 0..color.height {
+                for x in 0..color.width {
+                    let p = (y * color.width + x) as usize;
+                    let mut sum = Color::black();
+                    let mut total = 0.0;
+                    for (j, ky) in KERNEL.iter().enumerate() {
+                        for (i, kx) in KERNEL.iter().enumerate() {
+                            let qx = x as i64 + (i as i64 - 2) * step;
+                            let qy = y as i64 + (j as i64 - 2) * step;
+                            if qx < 0
+                                || qy < 0
+                                || qx >= color.width as i64
+                                || qy >= color.height as i64
+                            {
+                                continue;
+                            }
+                            let q = (qy as u32 * color.width + qx as u32) as usize;
+                            let weight = kx
+                                * ky
+                                * gaussian(
+                                    distance2(&current.pixels[p], &current.pixels[q]),
+                                    color_sigma,
+                                )
+                                * gaussian(
+                                    distance2(&aovs.normal.pixels[p], &aovs.normal.pixels[q]),
+                                    self.normal_sigma,
+                                )
+                                * gaussian(
+                                    distance2(&aovs.albedo.pixels[p], &aovs.albedo.pixels[q]),
+                                    self.albedo_sigma,
+                                )
+                                * self.depth_weight(
+                                    aovs.depth.pixels[p].red,
+                                    aovs.depth.pixels[q].red,
+                                );
+                            sum = sum + current.pixels[q] * weight;
+                            total += weight;
+                        }
+                    }
+                    // The center tap always has a weight, total is never zero.
+                    next.pixels[p] = sum * (1.0 / total);
+                }
+            }
+            current = next;
+        }
+        current
+    }
+
+    fn depth_weight(&self, depth: f32, other: f32) -> f32 {
+        if depth <= 0.0 || other <= 0.0 {
+            // Background on either side, only background blends with background.
+            return if depth == other { 1.0 } else { 0.0 };
+        }
+        (-(depth - other).abs() / (self.depth_sigma * depth)).exp()
+    }
+}
+
+fn distance2(a: &Color, b: &Color) -> f32 {
+    let d = *a - *b;
+    d.red * d.red + d.green * d.green + d.blue * d.blue
+}
+
+fn gaussian(distance2: f32, sigma: f32) -> f32 {
+    (-distance2 / (sigma * sigma)).exp()
+}
+
+#[cfg(test)]
+#[path = "./denoise_tests.rs"]
+mod denoise_tests;
@@ -0,0 +1,96 @@
+#[cfg(test)]
+mod tests {
+    use crate::denoise::*;
+
+    // Flat guides, as if the whole image was a single wall facing the camera.
+    fn flat_aovs(width: u32, height: u32) -> Aovs {
+        let mut aovs = Aovs::new(width, height);
+        for i in 0..(width * height) as usize {
+            aovs.depth.pixels[i] = Color::white() * 5.0;
+            aovs.normal.pixels[i] = Color {
+                red: 0.0,
+                green: 0.0,
+                blue: -1.0,
+            };
+            aovs.albedo.pixels[i] = Color::white() * 0.5;
+        }
+        aovs
+    }
+
+    fn noisy(width: u32, height: u32, level: f32) -> Framebuffer {
+        let mut fb = Framebuffer::new(width, height);
+        let mut seed: u32 = 12345;
+        for pixel in fb.pixels.iter_mut() {
+            seed = seed.wrapping_mul(1103515245).wrapping_add(12345);
+            let noise = ((seed >> 16) & 0x7fff) as f32 / 32767.0 - 0.5;
+            *pixel = Color::white() * (level + noise * 0.4);
+        }
+        fb
+    }
+
+    fn variance(fb: &Framebuffer) -> f32 {
+        let mean = fb.pixels.iter().map(|c| c.red).sum::<f32>() / fb.pixels.len() as f32;
+        fb.pixels
+            .iter()
+            .map(|c| (c.red - mean).powi(2))
+            .sum::<f32>()
+            / fb.pixels.len() as f32
+    }
+
+    #[test]
+    fn test_no_iterations_is_identity() {
+        let fb = noisy(8, 8, 0.5);
+        let denoiser = Denoiser {
+            iterations: 0,
+            ..Denoiser::default()
+        };
+        assert_eq!(denoiser.denoise(&fb, &flat_aovs(8, 8)), fb);
+    }
+
+    #[test]
+    fn test_removes_noise() {
+        let fb = noisy(32, 32, 0.5);
+        let denoised = Denoiser::default().denoise(&fb, &flat_aovs(32, 32));
+        assert!(variance(&denoised) < variance(&fb) * 0.1);
+    }
+
+    #[test]
+    fn test_keeps_geometry_edges() {
+        // Two walls meeting in the middle of the image, a dark one on the left and a bright one on
+        // the right, with the same noise.
+        let mut fb = noisy(32, 32, 0.2);
+        let bright = noisy(32, 32, 0.8);
+        let mut aovs = flat_aovs(32, 32);
+        for y in 0..32 {
+            for x in 16..32 {
+                let i = (y * 32 + x) as usize;
+                fb.pixels[i] = bright.pixels[i];
+                aovs.normal.pixels[i] = Color {
+                    red: 1.0,
+                    green: 0.0,
+                    blue: 0.0,
+                };
+            }
+        }
+        let denoised = Denoiser::default().denoise(&fb, &aovs);
+        for y in 0..32 {
+            assert!((denoised.get(15, y).red - 0.2).abs() < 0.1);
+            assert!((denoised.get(16, y).red - 0.8).abs() < 0.1);
+        }
+    }
+
+    #[test]
+    fn test_background_does_not_bleed() {
+        let mut fb = Framebuffer::new(8, 8);
+        let mut aovs = flat_aovs(8, 8);
+        for x in 0..8 {
+            fb.set(x, 0, Color::white());
+            for y in 1..8 {
+                aovs.depth.set(x, y, Color::black());
+            }
+        }
+        let denoised = Denoiser::default().denoise(&fb, &aovs);
+        assert_eq!(denoised.get(3, 1), Color::black());
+        assert_eq!(denoised.get(3, 0), Color::white());
+    }
+}
@@ -0,0 +1,138 @@
+use crate::color::*;
+use crate::tonemap::*;
+use image::codecs::hdr::HdrEncoder;
+use image::{DynamicImage, ImageResult, Rgb, Rgb32FImage, RgbaImage};
+use std::fs::File;
+use std::io::{BufWriter, Write};
+use std::path::Path;
+
+// Linear floating point image, colors are kept as rendered so values above 1.0 survive until
+// the image is written to an 8 bit format.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Framebuffer {
+    pub width: u32,
+    pub height: u32,
+    pub pixels: Vec<Color>,
+}
+
+impl Framebuffer {
+    pub fn new(width: u32, height: u32) -> Self {
+        Framebuffer {
+            width,
+            height,
+            pixels: vec![Color::black(); (width * height) as usize],
+        }
+    }
+
+    pub fn get(&self, x: u32, y: u32) -> Color {
+        self.pixels[(y * self.width + x) as usize]
+    }
+
+    pub fn set(&mut self, x: u32, y: u32, color: Color) {
+        self.pixels[(y * self.width + x) as usize] = color;
+    }
+
+    // Pastes another framebuffer with its top left corner at (x, y).
+    pub fn copy_from(&mut self, other: &Framebuffer, x: u32, y: u32) {
+        for j in 0..other.height {
+            for i in 0..other.width {
+                self.set(x + i, y + j, other.get(i, j));
+            }
+        }
+    }
+
+    // 8 bit sRGB copy of the image, channels are clamped to [0.0, 1.0]. Dithering trades the banding
+    // of smooth gradients for a fixed, barely visible pattern.
+    pub fn to_rgba8(&self, dither: bool) -> RgbaImage {
+        RgbaImage::from_fn(self.width, self.height, |x, y| {
+            let color = self.get(x, y).to_srgb();
+            let offset = if dither { dither_offset(x, y) } else { 0.0 };
+            image::Rgba([
+                quantize(color.red + offset),
+                quantize(color.green + offset),
+                quantize(color.blue + offset),
+                255,
+            ])
+        })
+    }
+
+    pub fn to_rgb32f(&self) -> Rgb32FImage {
+        Rgb32FImage::from_fn(self.width, self.height, |x, y| {
+            let color = self.get(x, y);
+            Rgb([color.red, color.green, color.blue])
+        })
+    }
+
+    // Writes the image in the format matching the file extension, .hdr, .pfm and .exr keep the
+    // linear floating point values, anything else is post processed then converted to 8 bits.
+    pub fn save<P: AsRef<Path>>(&self, path: P, post_process: &PostProcess) -> ImageResult<()> {
+        let path = path.as_ref();
+        match extension(path).as_deref() {
+            Some("hdr") => self.write_hdr(path),
+            Some("pfm") => self.write_pfm(path),
+            Some("exr") => DynamicImage::ImageRgb32F(self.to_rgb32f()).save(path),
+            _ => DynamicImage::ImageRgba8(post_process.to_rgba8(self)).save(path),
+        }
+    }
+
+    // Radiance RGBE file.
+    pub fn write_hdr<P: AsRef<Path>>(&self, path: P) -> ImageResult<()> {
+        let pixels: Vec<Rgb<f32>> = self
+            .pixels
+            .iter()
+            .map(|c| Rgb([c.red.max(0.0), c.green.max(0.0), c.blue.max(0.0)]))
+            .collect();
+        HdrEncoder::new(BufWriter::new(File::create(path)?)).encode(
+            &pixels,
+            self.width as usize,
+            self.height as usize,
+        )
+    }
+
+    // Portable float map, little endian, rows are stored from the bottom up.
+    pub fn write_pfm<P: AsRef<Path>>(&self, path: P) -> ImageResult<()> {
+        let mut file = BufWriter::new(File::create(path)?);
+        write!(file, "PF\n{} {}\n-1.0\n", self.width, self.height)?;
+        for y in (0..self.height).rev() {
+            for x in 0..self.width {
+                let color = self.get(x, y);
+                for channel in [color.red, color.green, color.blue] {
+                    file.write_all(&channel.to_le_bytes())?;
+                }
+            }
+        }
+        file.flush()?;
+        Ok(())
+    }
+}
+
+fn extension(path: &Path) -> Option<String> {
+    path.extension()
+        .and_then(|e| e.to_str())
+        .map(|e| e.to_lowercase())
+}
+
+// Formats written with linear floating point values by `save`.
+pub fn is_float_format<P: AsRef<Path>>(path: P) -> bool {
+    matches!(
+        extension(path.as_ref()).as_deref(),
+        Some("hdr" | "pfm" | "exr")
+    )
+}
+
+const BAYER: [[f32; 4]; 4] = [
+    [0.0, 8.0, 2.0, 10.0],
+    [12.0, 4.0, 14.0, 6.0],
+    [3.0, 11.0, 1.0, 9.0],
+    [15.0, 7.0, 13.0, 5.0],
+];
+
+// Ordered dither, less than half a step either way so flat areas keep their mean value. Being
+// tied to the pixel position, it does not crawl between animation frames.
+fn dither_offset(x: u32, y: u32) -> f32 {
+    ((BAYER[(y % 4) as usize][(x % 4) as usize] + 0.5) / 16.0 - 0.5) / 255.0
+}
+
+#[cfg(test)]
+#[path = "./framebuffer_tests.rs"]
+mod framebuffer_tests;
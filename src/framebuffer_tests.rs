@@ -0,0 +1,111 @@
+#[cfg(test)]
+mod tests {
+    use crate::framebuffer::*;
+
+    fn bright_framebuffer() -> Framebuffer {
+        let mut fb = Framebuffer::new(3, 2);
+        fb.set(
+            0,
+            0,
+            Color {
+                red: 4.0,
+                green: 0.5,
+                blue: 0.25,
+            },
+        );
+        fb.set(2, 1, Color::white());
+        fb
+    }
+
+    fn temp_path(name: &str) -> std::path::PathBuf {
+        std::env::temp_dir().join(format!("rustracer_{}_{}", std::process::id(), name))
+    }
+
+    #[test]
+    fn test_get_set() {
+        let fb = bright_framebuffer();
+        assert_eq!(fb.pixels.len(), 6);
+        assert_eq!(fb.get(2, 1), Color::white());
+        assert_eq!(fb.get(1, 1), Color::black());
+    }
+
+    #[test]
+    fn test_copy_from() {
+        let mut fb = Framebuffer::new(6, 2);
+        fb.copy_from(&bright_framebuffer(), 3, 0);
+        assert_eq!(fb.get(5, 1), Color::white());
+        assert_eq!(fb.get(2, 1), Color::black());
+    }
+
+    #[test]
+    fn test_rgba8_clamps_and_encodes() {
+        let img = bright_framebuffer().to_rgba8(false);
+        assert_eq!(img.get_pixel(0, 0).0, [255, 188, 137, 255]);
+        assert_eq!(img.get_pixel(1, 0).0, [0, 0, 0, 255]);
+        assert_eq!(img.get_pixel(2, 1).0, [255, 255, 255, 255]);
+    }
+
+    #[test]
+    fn test_rgba8_dither() {
+        let mut fb = Framebuffer::new(4, 4);
+        for pixel in fb.pixels.iter_mut() {
+            *pixel = Color::white() * 0.5;
+        }
+        let plain = fb.to_rgba8(false);
+        let dithered = fb.to_rgba8(true);
+        assert!(plain.pixels().all(|p| p.0[0] == 188));
+        assert!(dithered.pixels().all(|p| p.0[0] == 187 || p.0[0] == 188));
+        assert!(dithered.pixels().any(|p| p.0[0] == 187));
+        // Same pattern on every call.
+        assert_eq!(dithered, fb.to_rgba8(true));
+        // Black and white stay put.
+        assert!(Framebuffer::new(4, 4)
+            .to_rgba8(true)
+            .pixels()
+            .all(|p| p.0 == [0, 0, 0, 255]));
+    }
+
+    #[test]
+    fn test_write_pfm() {
+        let path = temp_path("test.pfm");
+        bright_framebuffer()
+            .save(&path, &PostProcess::default())
+            .unwrap();
+        let bytes = std::fs::read(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+        let header = b"PF\n3 2\n-1.0\n";
+        assert_eq!(&bytes[..header.len()], header);
+        assert_eq!(bytes.len(), header.len() + 3 * 2 * 3 * 4);
+        // Bottom row first, so the top left pixel starts the second row.
+        let top_left = header.len() + 3 * 3 * 4;
+        let red = f32::from_le_bytes(bytes[top_left..top_left + 4].try_into().unwrap());
+        assert_eq!(red, 4.0);
+    }
+
+    #[test]
+    fn test_write_hdr_keeps_range() {
+        let path = temp_path("test.hdr");
+        bright_framebuffer()
+            .save(&path, &PostProcess::default())
+            .unwrap();
+        let reader = std::io::BufReader::new(std::fs::File::open(&path).unwrap());
+        let decoder = image::codecs::hdr::HdrDecoder::new(reader).unwrap();
+        assert_eq!(decoder.metadata().width, 3);
+        assert_eq!(decoder.metadata().height, 2);
+        let pixels = decoder.read_image_hdr().unwrap();
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(pixels[0].0, [4.0, 0.5, 0.25]);
+    }
+
+    #[test]
+    fn test_write_exr_keeps_range() {
+        let path = temp_path("test.exr");
+        bright_framebuffer()
+            .save(&path, &PostProcess::default())
+            .unwrap();
+        let img = image::open(&path).unwrap().to_rgb32f();
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(img.get_pixel(0, 0).0, [4.0, 0.5, 0.25]);
+        assert_eq!(img.get_pixel(2, 1).0, [1.0, 1.0, 1.0]);
+    }
+}
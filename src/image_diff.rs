@@ -0,0 +1,111 @@
+use crate::debug_view::*;
+use crate::framebuffer::*;
+use image::RgbaImage;
+
+// Differences between two 8 bit images of the same size. Channels are compared on their sRGB
+// values, which follow how different colors look more closely than linear ones.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ImageDiff {
+    pub width: u32,
+    pub height: u32,
+    // Largest channel difference of every pixel, row by row.
+    pub errors: Vec<u8>,
+    // Sum of the squared differences of all the color channels.
+    pub squared_error: f64,
+}
+
+impl ImageDiff {
+    pub fn new(a: &RgbaImage, b: &RgbaImage) -> Result<ImageDiff, String> {
+        if a.dimensions() != b.dimensions() {
+            return Err(format!(
+                "sizes differ, {}x{} and {}x{}",
+                a.width(),
+                a.height(),
+                b.width(),
+                b.height()
+            ));
+        }
+        let mut squared_error = 0.0;
+        let errors = a
+            .pixels()
+            .zip(b.pixels())
+            .map(|(p, q)| {
+                let channels = (0..3).map(|c| p.0[c].abs_diff(q.0[c]));
+                squared_error += channels.clone().map(|e| (e as f64).powi(2)).sum::<f64>();
+                channels.max().unwrap_or(0)
+            })
+            .collect();
+        Ok(ImageDiff {
+            width: a.width(),
+            height: a.height(),
+            errors,
+            squared_error,
+        })
+    }
+
+    // Root mean square of the channel differences, in 8 bit steps.
+    pub fn rmse(&self) -> f64 {
+        let channels = 3 * self.errors.len();
+        if channels == 0 {
+            return 0.0;
+        }
+        (self.squared_error / channels as f64).sqrt()
+    }
+
+    // Peak signal to noise ratio in decibels, higher is closer and identical images are infinite.
+    pub fn psnr(&self) -> f64 {
+        20.0 * (255.0 / self.rmse()).log10()
+    }
+
+    pub fn max_channel_error(&self) -> u8 {
+        self.errors.iter().copied().max().unwrap_or(0)
+    }
+
+    // Pixels with a channel off by more than `threshold`.
+    pub fn differing_pixels(&self, threshold: u8) -> usize {
+        self.errors.iter().filter(|e| **e > threshold).count()
+    }
+
+    // False color picture of the errors, black where the images match and white from an error of
+    // `scale` up.
+    pub fn heatmap(&self, scale: u8) -> RgbaImage {
+        let mut framebuffer = Framebuffer::new(self.width, self.height);
+        for (pixel, error) in framebuffer.pixels.iter_mut().zip(&self.errors) {
+            *pixel = heat(*error as f32 / scale.max(1) as f32);
+        }
+        framebuffer.to_rgba8(false)
+    }
+}
+
+// How far a render may drift from its reference. A few pixels can move a little, on edges or
+// after rounding changes, but none may be far off.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Tolerance {
+    // Channel difference below which pixels count as the same.
+    pub channel: u8,
+    // Share of the pixels allowed to differ by more than `channel`.
+    pub pixels: f64,
+    // Channel difference no pixel may go over.
+    pub max: u8,
+}
+
+impl Default for Tolerance {
+    fn default() -> Self {
+        Tolerance {
+            channel: 2,
+            pixels: 0.005,
+            max: 32,
+        }
+    }
+}
+
+impl Tolerance {
+    pub fn accepts(&self, diff: &ImageDiff) -> bool {
+        let allowed = (self.pixels * diff.errors.len() as f64).floor() as usize;
+        diff.max_channel_error() <= self.max && diff.differing_pixels(self.channel) <= allowed
+    }
+}
+
+#[cfg(test)]
+#[path = "./image_diff_tests.rs"]
+mod image_diff_tests;
@@ -0,0 +1,78 @@
+#[cfg(test)]
+mod tests {
+    use crate::image_diff::*;
+    use image::Rgba;
+
+    fn gray(width: u32, height: u32, value: u8) -> RgbaImage {
+        RgbaImage::from_pixel(width, height, Rgba([value, value, value, 255]))
+    }
+
+    #[test]
+    fn test_identical_images() {
+        let diff = ImageDiff::new(&gray(4, 3, 100), &gray(4, 3, 100)).unwrap();
+        assert_eq!(diff.max_channel_error(), 0);
+        assert_eq!(diff.differing_pixels(0), 0);
+        assert_eq!(diff.rmse(), 0.0);
+        assert_eq!(diff.psnr(), f64::INFINITY);
+        assert!(Tolerance::default().accepts(&diff));
+        assert!(diff.heatmap(8).pixels().all(|p| *p == Rgba([0, 0, 0, 255])));
+    }
+
+    #[test]
+    fn test_errors_are_per_pixel_maximum() {
+        let a = gray(2, 1, 100);
+        let mut b = a.clone();
+        b.put_pixel(1, 0, Rgba([103, 90, 100, 0]));
+        let diff = ImageDiff::new(&a, &b).unwrap();
+        // Alpha is not compared.
+        assert_eq!(diff.errors, vec![0, 10]);
+        assert_eq!(diff.max_channel_error(), 10);
+        assert_eq!(diff.differing_pixels(9), 1);
+        assert_eq!(diff.differing_pixels(10), 0);
+        // 3 and 10 over 6 channels.
+        assert_eq!(diff.squared_error, 109.0);
+        assert!((diff.rmse() - (109.0_f64 / 6.0).sqrt()).abs() < 1e-12);
+        assert!((diff.psnr() - 35.54).abs() < 0.01);
+        assert_eq!(
+            diff.heatmap(10).get_pixel(1, 0),
+            &Rgba([255, 255, 255, 255])
+        );
+    }
+
+    #[test]
+    fn test_size_mismatch() {
+        assert_eq!(
+            ImageDiff::new(&gray(2, 2, 0), &gray(2, 3, 0)),
+            Err("sizes differ, 2x2 and 2x3".to_string())
+        );
+    }
+
+    #[test]
+    fn test_tolerance() {
+        let a = gray(10, 10, 50);
+        let mut b = a.clone();
+        b.put_pixel(0, 0, Rgba([60, 50, 50, 255]));
+        let diff = ImageDiff::new(&a, &b).unwrap();
+        let tolerance = Tolerance {
+            channel: 2,
+            pixels: 0.01,
+            max: 16,
+        };
+        assert!(tolerance.accepts(&diff));
+        assert!(!Tolerance {
+            max: 8,
+            ..tolerance
+        }
+        .accepts(&diff));
+        assert!(!Tolerance {
+            pixels: 0.0,
+            ..tolerance
+        }
+        .accepts(&diff));
+        // Small differences everywhere pass as long as they stay under the channel threshold.
+        let diff = ImageDiff::new(&a, &gray(10, 10, 52)).unwrap();
+        assert!(tolerance.accepts(&diff));
+        let diff = ImageDiff::new(&a, &gray(10, 10, 53)).unwrap();
+        assert!(!tolerance.accepts(&diff));
+    }
+}
@@ -0,0 +1,223 @@
+use crate::color::*;
+use crate::intersection::*;
+use crate::light::*;
+use crate::point::*;
+use crate::ray::*;
+use crate::vector3::*;
+use crate::world::*;
+use std::fmt::Write;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum RayKind {
+    Camera,
+    Reflected,
+    Refracted,
+}
+
+// A ray and the reflection and refraction rays spawned where it hit.
+#[derive(Clone, Debug, PartialEq)]
+pub struct RayNode {
+    pub kind: RayKind,
+    pub ray: Ray,
+    // Index of the object hit in the world and distance along the ray.
+    pub hit: Option<(usize, f64)>,
+    // Shading state at the hit and the light gathered there, what the inspector report prints.
+    pub comps: Option<Computations>,
+    pub contributions: ShadeContributions,
+    // Color carried back by the ray, before the parent weights it.
+    pub color: Color,
+    pub children: Vec<RayNode>,
+}
+
+// Follows a ray the way `color_at` does, keeping every ray on the way.
+pub fn trace_tree(world: &World, ray: &Ray, kind: RayKind, remaining: i32) -> RayNode {
+    let (index, comps) = match hit_indexed(ray, world) {
+        Some(hit) => hit,
+        None => {
+            return RayNode {
+                kind,
+                ray: *ray,
+                hit: None,
+                comps: None,
+                contributions: ShadeContributions {
+                    direct: Color::black(),
+                    reflected: Color::black(),
+                    refracted: Color::black(),
+                },
+                color: Color::black(),
+                children: vec![],
+            }
+        }
+    };
+    let material = comps.object.material();
+    let mut children = vec![];
+    if remaining > 0 && material.reflective > 0.0 {
+        children.push(trace_tree(
+            world,
+            &reflected_ray(&comps),
+            RayKind::Reflected,
+            remaining - 1,
+        ));
+    }
+    if remaining > 0 && material.transparency > 0.0 {
+        if let Some(refracted) = refracted_ray(&comps) {
+            children.push(trace_tree(
+                world,
+                &refracted,
+                RayKind::Refracted,
+                remaining - 1,
+            ));
+        }
+    }
+    let contributions = shade_hit_contributions(world, &comps, remaining);
+    RayNode {
+        kind,
+        ray: *ray,
+        hit: Some((index, comps.distance)),
+        comps: Some(comps),
+        contributions,
+        color: contributions.total(),
+        children,
+    }
+}
+
+// Everything that went into the color of the first hit of a ray, as printed by the preview when a
+// pixel is clicked. The ray tree of reflections and refractions is appended when asked for.
+pub fn inspect_ray(world: &World, ray: &Ray, remaining: i32, tree: bool) -> String {
+    let mut out = String::new();
+    // Writing to a String cannot fail.
+    let _ = write_report(&mut out, world, ray, remaining, tree);
+    out
+}
+
+fn write_report(
+    out: &mut String,
+    world: &World,
+    ray: &Ray,
+    remaining: i32,
+    tree: bool,
+) -> std::fmt::Result {
+    writeln!(
+        out,
+        "ray origin {} direction {} time {}",
+        point(&ray.origin),
+        vector(&ray.direction),
+        ray.time
+    )?;
+    let root = trace_tree(world, ray, RayKind::Camera, remaining);
+    let (index, comps) = match (root.hit, &root.comps) {
+        (Some((index, _)), Some(comps)) => (index, comps),
+        _ => return writeln!(out, "no hit"),
+    };
+    let m = comps.object.material();
+    writeln!(
+        out,
+        "hit object {} ({}) at distance {:.6}",
+        index,
+        comps.object.name(),
+        comps.distance
+    )?;
+    writeln!(out, "  point       {}", point(&comps.point))?;
+    writeln!(out, "  normal      {}", vector(&comps.normalv))?;
+    writeln!(out, "  eye         {}", vector(&comps.eyev))?;
+    writeln!(out, "  reflect     {}", vector(&comps.reflectv))?;
+    writeln!(out, "  over point  {}", point(&comps.over_point))?;
+    writeln!(out, "  under point {}", point(&comps.under_point))?;
+    writeln!(
+        out,
+        "  inside {} n1 {} n2 {}",
+        comps.inside, comps.n1, comps.n2
+    )?;
+    writeln!(
+        out,
+        "material color {} surface {} ambient {} diffuse {} specular {} shininess {}",
+        color(&m.color),
+        color(&m.color_at(&comps.object, &comps.point)),
+        m.ambient,
+        m.diffuse,
+        m.specular,
+        m.shininess
+    )?;
+    writeln!(
+        out,
+        "  reflective {} transparency {} refractive index {} pattern {}",
+        m.reflective,
+        m.transparency,
+        m.refractive_index,
+        m.pattern.is_some()
+    )?;
+    for (i, light) in world.lights.iter().enumerate() {
+        let shadowed = is_shadowed(world, &comps.over_point, light, comps.time);
+        let contribution = lighting(
+            m,
+            &comps.object,
+            light,
+            &comps.point,
+            &comps.eyev,
+            &comps.normalv,
+            shadowed,
+        );
+        writeln!(
+            out,
+            "light {} at {}: shadowed {} contribution {}",
+            i,
+            point(&light.position()),
+            shadowed,
+            color(&contribution)
+        )?;
+    }
+    let contributions = root.contributions;
+    writeln!(
+        out,
+        "direct {} reflected {} refracted {} total {}",
+        color(&contributions.direct),
+        color(&contributions.reflected),
+        color(&contributions.refracted),
+        color(&contributions.total())
+    )?;
+    if tree {
+        writeln!(out, "ray tree:")?;
+        write_tree(out, &root, 1)?;
+    }
+    Ok(())
+}
+
+fn write_tree(out: &mut String, node: &RayNode, depth: usize) -> std::fmt::Result {
+    let kind = match node.kind {
+        RayKind::Camera => "camera",
+        RayKind::Reflected => "reflected",
+        RayKind::Refracted => "refracted",
+    };
+    match node.hit {
+        Some((index, distance)) => writeln!(
+            out,
+            "{}{} ray -> object {} at {:.6}, color {}",
+            "  ".repeat(depth),
+            kind,
+            index,
+            distance,
+            color(&node.color)
+        )?,
+        None => writeln!(out, "{}{} ray -> miss", "  ".repeat(depth), kind)?,
+    }
+    for child in &node.children {
+        write_tree(out, child, depth + 1)?;
+    }
+    Ok(())
+}
+
+fn point(p: &Point) -> String {
+    format!("({:.6}, {:.6}, {:.6})", p.x, p.y, p.z)
+}
+
+fn vector(v: &Vector3) -> String {
+    format!("({:.6}, {:.6}, {:.6})", v.x, v.y, v.z)
+}
+
+fn color(c: &Color) -> String {
+    format!("({:.4}, {:.4}, {:.4})", c.red, c.green, c.blue)
+}
+
+#[cfg(test)]
+#[path = "./inspect_tests.rs"]
+mod inspect_tests;
@@ -0,0 +1,109 @@
+#[cfg(test)]
+mod tests {
+    use crate::inspect::*;
+    use crate::material::*;
+    use crate::matrix::*;
+    use crate::objects::plane::*;
+    use crate::objects::*;
+    use std::sync::Arc;
+
+    fn ray(origin: Point, direction: Vector3) -> Ray {
+        Ray {
+            origin,
+            direction,
+            time: 0.0,
+        }
+    }
+
+    fn mirror_world() -> World {
+        let mut world = World::default();
+        world.objects.push(Arc::new(
+            Object::new(
+                Plane,
+                Material {
+                    reflective: 0.5,
+                    ..Material::default()
+                },
+            )
+            .set_transform(Matrix44::translation(0.0, -1.0, 0.0)),
+        ));
+        world
+    }
+
+    fn mirror_ray() -> Ray {
+        let half = 2.0_f64.sqrt() / 2.0;
+        ray(
+            Point {
+                x: 0.0,
+                y: 0.0,
+                z: -3.0,
+            },
+            Vector3 {
+                x: 0.0,
+                y: -half,
+                z: half,
+            },
+        )
+    }
+
+    #[test]
+    fn test_report() {
+        let r = ray(
+            Point {
+                x: 0.0,
+                y: 0.0,
+                z: -5.0,
+            },
+            Vector3 {
+                x: 0.0,
+                y: 0.0,
+                z: 1.0,
+            },
+        );
+        let report = inspect_ray(&World::default(), &r, 5, false);
+        assert!(report.contains("hit object 0 (sphere) at distance 4.000000"));
+        assert!(report.contains("  normal      (0.000000, 0.000000, -1.000000)"));
+        assert!(report.contains("  inside false n1 1 n2 1"));
+        assert!(report.contains("light 0 at (-10.000000, 10.000000, -10.000000): shadowed false"));
+        assert!(report.contains("total (0.3807, 0.4758, 0.2855)"));
+        assert!(!report.contains("ray tree"));
+    }
+
+    #[test]
+    fn test_report_miss() {
+        let r = ray(
+            Point {
+                x: 0.0,
+                y: 0.0,
+                z: -5.0,
+            },
+            Vector3 {
+                x: 0.0,
+                y: 1.0,
+                z: 0.0,
+            },
+        );
+        let report = inspect_ray(&World::default(), &r, 5, true);
+        assert!(report.ends_with("no hit\n"));
+    }
+
+    #[test]
+    fn test_ray_tree() {
+        let world = mirror_world();
+        let tree = trace_tree(&world, &mirror_ray(), RayKind::Camera, 5);
+        assert_eq!(tree.hit.unwrap().0, 2);
+        assert_eq!(tree.color, color_at(&world, &mirror_ray(), 5));
+        // The report reads the hit back from the tree.
+        assert_eq!(tree.comps.as_ref().unwrap().distance, tree.hit.unwrap().1);
+        assert_eq!(tree.contributions.total(), tree.color);
+        assert_eq!(tree.children.len(), 1);
+        assert_eq!(tree.children[0].kind, RayKind::Reflected);
+        assert_eq!(tree.children[0].hit.unwrap().0, 0);
+        // The recursion budget bounds the tree like it bounds shading.
+        let flat = trace_tree(&world, &mirror_ray(), RayKind::Camera, 0);
+        assert!(flat.children.is_empty());
+        let report = inspect_ray(&world, &mirror_ray(), 5, true);
+        assert!(report.contains("ray tree:\n  camera ray -> object 2"));
+        assert!(report.contains("\n    reflected ray -> object 0"));
+    }
+}
@@ -3,15 +3,25 @@ use crate::light::*;
 use crate::objects::*;
 use crate::point::*;
 use crate::ray::*;
+use crate::stats::{self, RayCategory};
 use crate::vector3::*;
 use crate::world::*;
+use std::sync::Arc;
 
-#[derive(Clone, Debug, PartialEq)]
+#[derive(Clone, Debug)]
 pub struct Intersection {
-    pub object: Object,
+    pub object: Arc<Object>,
     pub distance: f64,
 }
 
+// Intersections are the same when they hit the same object at the same distance, two identical
+// looking objects are still told apart.
+impl PartialEq for Intersection {
+    fn eq(&self, other: &Self) -> bool {
+        self.distance == other.distance && Arc::ptr_eq(&self.object, &other.object)
+    }
+}
+
 pub fn intersect_world<'a>(ray: &Ray, world: &'a World) -> Vec<Intersection> {
     let mut t: Vec<Intersection> = world
         .objects
@@ -23,18 +33,48 @@ pub fn intersect_world<'a>(ray: &Ray, world: &'a World) -> Vec<Intersection> {
     t
 }
 
+// Same as `intersect_world`, along with the index in the world of the object each intersection
+// belongs to.
+pub fn intersect_world_indexed(ray: &Ray, world: &World) -> Vec<(usize, Intersection)> {
+    let mut t: Vec<(usize, Intersection)> = world
+        .objects
+        .iter()
+        .enumerate()
+        .filter_map(|(index, object)| {
+            object
+                .intersect(ray)
+                .map(|xs| xs.into_iter().map(move |x| (index, x)))
+        })
+        .flatten()
+        .collect();
+    t.sort_by(|a, b| a.1.distance.partial_cmp(&b.1.distance).unwrap());
+    t
+}
+
+// First hit in front of the ray, with the index in the world of the object hit, ready to shade.
+pub fn hit_indexed(ray: &Ray, world: &World) -> Option<(usize, Computations)> {
+    let intersections = intersect_world_indexed(ray, world);
+    let (index, hit) = intersections
+        .iter()
+        .find(|(_, i)| i.distance.is_sign_positive())?;
+    let collection: Vec<Intersection> = intersections.iter().map(|(_, i)| i.clone()).collect();
+    Some((*index, prepare_computations(hit, ray, &collection)))
+}
+
+#[derive(Clone, Debug, PartialEq)]
 pub struct Computations {
     pub distance: f64,
     pub point: Point,
     pub eyev: Vector3,
     pub reflectv: Vector3,
     pub normalv: Vector3,
-    pub object: Object,
+    pub object: Arc<Object>,
     pub inside: bool,
     pub over_point: Point,
     pub under_point: Point,
     pub n1: f32,
     pub n2: f32,
+    pub time: f64,
 }
 
 pub fn prepare_computations(
@@ -42,7 +82,8 @@ pub fn prepare_computations(
     ray: &Ray,
     intersections_collection: &Vec<Intersection>,
 ) -> Computations {
-    let mut containers: Vec<Object> = vec![];
+    // Objects the ray is inside of, by identity, the innermost last.
+    let mut containers: Vec<Arc<Object>> = vec![];
     let mut n1 = 1.0;
     let mut n2 = 1.0;
     for inter in intersections_collection {
@@ -54,10 +95,10 @@ pub fn prepare_computations(
             }
         }
 
-        if containers.contains(&inter.object) {
-            containers.retain(|obj| obj != &inter.object);
+        if containers.iter().any(|obj| Arc::ptr_eq(obj, &inter.object)) {
+            containers.retain(|obj| !Arc::ptr_eq(obj, &inter.object));
         } else {
-            containers.push(inter.object.clone());
+            containers.push(Arc::clone(&inter.object));
         }
 
         if inter == intersection {
@@ -81,7 +122,7 @@ pub fn prepare_computations(
     let reflectv = ray.direction.reflect(&normalv);
     Computations {
         distance: intersection.distance,
-        object: intersection.object.clone(),
+        object: Arc::clone(&intersection.object),
         point,
         eyev,
         normalv,
@@ -92,6 +133,7 @@ pub fn prepare_computations(
         under_point: point - normalv * 1e-11,
         n1,
         n2,
+        time: ray.time,
     }
 }
 
@@ -104,30 +146,61 @@ pub fn hit(intersections: Vec<Intersection>) -> Option<Intersection> {
         .min_by(|a, b| a.distance.partial_cmp(&b.distance).unwrap())
 }
 
+// What a hit sends back to the eye, split by where the light comes from. The parts add up to the
+// shaded color.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ShadeContributions {
+    // Light reaching the surface straight from the light sources, ambient included.
+    pub direct: Color,
+    pub reflected: Color,
+    pub refracted: Color,
+}
+
+impl ShadeContributions {
+    pub fn total(&self) -> Color {
+        self.direct + self.reflected + self.refracted
+    }
+}
+
 pub fn shade_hit(world: &World, computations: &Computations, remaining: i32) -> Color {
-    world.lights.iter().fold(Color::black(), |sum, light| {
-        let lighting = lighting(
+    shade_hit_contributions(world, computations, remaining).total()
+}
+
+pub fn shade_hit_contributions(
+    world: &World,
+    computations: &Computations,
+    remaining: i32,
+) -> ShadeContributions {
+    let direct = world.lights.iter().fold(Color::black(), |sum, light| {
+        sum + lighting(
             computations.object.material(),
             &computations.object,
             light,
             &computations.point,
             &computations.eyev,
             &computations.normalv,
-            is_shadowed(world, &computations.over_point, light),
-        );
-        let reflected_color = reflected_color(world, &computations, remaining);
-        let refracted_color = refracted_color(world, computations, remaining);
-        if computations.object.material().reflective > 0.0
-            && computations.object.material().transparency > 0.0
-        {
-            let reflectance = schlick(&computations) as f32;
-            return sum
-                + lighting
-                + reflected_color * reflectance
-                + refracted_color * (1.0 - reflectance);
-        }
-        sum + lighting + reflected_color + refracted_color
-    })
+            is_shadowed(world, &computations.over_point, light, computations.time),
+        )
+    });
+    // Reflected and refracted rays already gather every light, add them once whatever the number
+    // of lights.
+    let reflected = reflected_color(world, computations, remaining);
+    let refracted = refracted_color(world, computations, remaining);
+    if computations.object.material().reflective > 0.0
+        && computations.object.material().transparency > 0.0
+    {
+        let reflectance = schlick(computations) as f32;
+        return ShadeContributions {
+            direct,
+            reflected: reflected * reflectance,
+            refracted: refracted * (1.0 - reflectance),
+        };
+    }
+    ShadeContributions {
+        direct,
+        reflected,
+        refracted,
+    }
 }
 
 pub fn color_at(world: &World, ray: &Ray, remaining: i32) -> Color {
@@ -149,31 +222,49 @@ pub fn reflected_color(world: &World, comps: &Computations, remaining: i32) -> C
     if comps.object.material().reflective == 0.0 {
         return Color::black();
     }
-    let reflected_ray = Ray {
+    let color = stats::bounce(RayCategory::Reflection, || {
+        color_at(world, &reflected_ray(comps), remaining - 1)
+    });
+    color * comps.object.material().reflective
+}
+
+pub fn reflected_ray(comps: &Computations) -> Ray {
+    Ray {
         origin: comps.over_point,
         direction: comps.reflectv,
-    };
-    let color = color_at(world, &reflected_ray, remaining - 1);
-    color * comps.object.material().reflective
+        time: comps.time,
+    }
 }
 
 pub fn refracted_color(world: &World, comps: &Computations, remaining: i32) -> Color {
     if comps.object.material().transparency == 0.0 || remaining <= 0 {
         return Color::black();
     }
+    match refracted_ray(comps) {
+        Some(ray) => {
+            stats::bounce(RayCategory::Refraction, || {
+                color_at(world, &ray, remaining - 1)
+            }) * comps.object.material().transparency
+        }
+        None => Color::black(),
+    }
+}
+
+// Ray going through the surface, None under total internal reflection.
+pub fn refracted_ray(comps: &Computations) -> Option<Ray> {
     let n_ratio = (comps.n1 / comps.n2) as f64;
     let cos_i = comps.eyev.dot(&comps.normalv);
     let sin2_t = n_ratio.powi(2) * (1.0 - cos_i.powi(2));
     if sin2_t > 1.0 {
-        return Color::black();
+        return None;
     }
     let cos_t = (1.0 - sin2_t).sqrt();
     let direction = comps.normalv * (n_ratio * cos_i - cos_t) - comps.eyev * n_ratio;
-    let refract_ray = Ray {
+    Some(Ray {
         origin: comps.under_point,
-        direction: direction,
-    };
-    color_at(world, &refract_ray, remaining - 1) * comps.object.material().transparency
+        direction,
+        time: comps.time,
+    })
 }
 
 pub fn schlick(comps: &Computations) -> f64 {
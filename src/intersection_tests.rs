@@ -9,17 +9,17 @@ mod tests {
     use crate::objects::sphere::*;
     use crate::patterns::checker::*;
     use crate::patterns::solid_color::*;
-    use crate::patterns::Pattern;
+    use crate::patterns::{Pattern, TestPattern};
     use crate::world;
 
     #[test]
     fn test_smallest_intersection() {
         let i1 = Intersection {
-            object: Object::Sphere(Sphere::new(&&Material::default())),
+            object: Arc::new(Object::new(Sphere, Material::default())),
             distance: 1.0,
         };
         let i2 = Intersection {
-            object: Object::Sphere(Sphere::new(&&Material::default())),
+            object: Arc::new(Object::new(Sphere, Material::default())),
             distance: 2.0,
         };
         assert_eq!(hit(vec![i1.clone(), i2]).unwrap(), i1);
@@ -28,11 +28,11 @@ mod tests {
     #[test]
     fn test_smallest_intersection_2() {
         let i1 = Intersection {
-            object: Object::Sphere(Sphere::new(&&Material::default())),
+            object: Arc::new(Object::new(Sphere, Material::default())),
             distance: -1.0,
         };
         let i2 = Intersection {
-            object: Object::Sphere(Sphere::new(&&Material::default())),
+            object: Arc::new(Object::new(Sphere, Material::default())),
             distance: 2.0,
         };
         assert_eq!(hit(vec![i1, i2.clone()]).unwrap(), i2);
@@ -41,11 +41,11 @@ mod tests {
     #[test]
     fn test_smallest_intersection_3() {
         let i1 = Intersection {
-            object: Object::Sphere(Sphere::new(&Material::default())),
+            object: Arc::new(Object::new(Sphere, Material::default())),
             distance: -2.0,
         };
         let i2 = Intersection {
-            object: Object::Sphere(Sphere::new(&Material::default())),
+            object: Arc::new(Object::new(Sphere, Material::default())),
             distance: -1.0,
         };
         assert!(hit(vec![i1, i2]) == None);
@@ -54,19 +54,19 @@ mod tests {
     #[test]
     fn test_smallest_intersection_4() {
         let i1 = Intersection {
-            object: Object::Sphere(Sphere::new(&Material::default())),
+            object: Arc::new(Object::new(Sphere, Material::default())),
             distance: 5.0,
         };
         let i2 = Intersection {
-            object: Object::Sphere(Sphere::new(&Material::default())),
+            object: Arc::new(Object::new(Sphere, Material::default())),
             distance: 7.0,
         };
         let i3 = Intersection {
-            object: Object::Sphere(Sphere::new(&Material::default())),
+            object: Arc::new(Object::new(Sphere, Material::default())),
             distance: -3.0,
         };
         let i4 = Intersection {
-            object: Object::Sphere(Sphere::new(&Material::default())),
+            object: Arc::new(Object::new(Sphere, Material::default())),
             distance: 2.0,
         };
         assert_eq!(hit(vec![i1, i2, i3, i4.clone()]).unwrap(), i4);
@@ -85,8 +85,9 @@ mod tests {
                 y: 0.0,
                 z: 1.0,
             },
+            time: 0.0,
         };
-        let sphere = Object::Sphere(Sphere::new(&Material::default()));
+        let sphere = Arc::new(Object::new(Sphere, Material::default()));
         let i = sphere.intersect(&ray);
         assert!(Option::is_some(&i));
         let u = i.unwrap();
@@ -107,8 +108,9 @@ mod tests {
                 y: 0.0,
                 z: 1.0,
             },
+            time: 0.0,
         };
-        let sphere = Object::Sphere(Sphere::new(&Material::default()));
+        let sphere = Arc::new(Object::new(Sphere, Material::default()));
         let i = sphere.intersect(&ray);
         assert_eq!(i, None);
     }
@@ -126,8 +128,9 @@ mod tests {
                 y: 0.0,
                 z: 1.0,
             },
+            time: 0.0,
         };
-        let sphere = Object::Sphere(Sphere::new(&Material::default()));
+        let sphere = Arc::new(Object::new(Sphere, Material::default()));
         let i = sphere.intersect(&ray);
         assert!(Option::is_some(&i));
         let u = i.unwrap();
@@ -148,8 +151,9 @@ mod tests {
                 y: 0.0,
                 z: 1.0,
             },
+            time: 0.0,
         };
-        let sphere = Object::Sphere(Sphere::new(&Material::default()));
+        let sphere = Arc::new(Object::new(Sphere, Material::default()));
         let i = sphere.intersect(&ray);
         assert!(Option::is_some(&i));
         let u = i.unwrap();
@@ -171,6 +175,7 @@ mod tests {
                 y: 0.0,
                 z: 1.0,
             },
+            time: 0.0,
         };
         let intersections = intersect_world(&ray, &world);
         assert_eq!(intersections.len(), 4);
@@ -193,8 +198,9 @@ mod tests {
                 y: 0.0,
                 z: 1.0,
             },
+            time: 0.0,
         };
-        let sphere = Object::Sphere(Sphere::new(&Material::default()));
+        let sphere = Arc::new(Object::new(Sphere, Material::default()));
         let i = Intersection {
             object: sphere,
             distance: 4.0,
@@ -241,8 +247,9 @@ mod tests {
                 y: 0.0,
                 z: 1.0,
             },
+            time: 0.0,
         };
-        let sphere = Object::Sphere(Sphere::new(&Material::default()));
+        let sphere = Arc::new(Object::new(Sphere, Material::default()));
         let i = Intersection {
             object: sphere,
             distance: 4.0,
@@ -264,8 +271,9 @@ mod tests {
                 y: 0.0,
                 z: 1.0,
             },
+            time: 0.0,
         };
-        let sphere = Object::Sphere(Sphere::new(&Material::default()));
+        let sphere = Arc::new(Object::new(Sphere, Material::default()));
         let i = Intersection {
             object: sphere,
             distance: 1.0,
@@ -312,6 +320,7 @@ mod tests {
                 y: 0.0,
                 z: 1.0,
             },
+            time: 0.0,
         };
         let shape = &world.objects[0];
         let i = Intersection {
@@ -356,6 +365,7 @@ mod tests {
                 y: 0.0,
                 z: 1.0,
             },
+            time: 0.0,
         };
         let shape = &world.objects[1];
         let i = Intersection {
@@ -384,11 +394,11 @@ mod tests {
             },
             color: Color::default(),
         })];
-        let sphere1 = Object::Sphere(Sphere::new(&Material::default()));
-        let sphere2 = Object::Sphere(Sphere::new(&Material::default()))
+        let sphere1 = Object::new(Sphere, Material::default());
+        let sphere2 = Object::new(Sphere, Material::default())
             .set_transform(Matrix44::translation(0.0, 0.0, 10.0));
         let world = World {
-            objects: vec![sphere1, sphere2],
+            objects: vec![Arc::new(sphere1), Arc::new(sphere2)],
             lights,
         };
         let r = Ray {
@@ -402,9 +412,10 @@ mod tests {
                 y: 0.0,
                 z: 1.0,
             },
+            time: 0.0,
         };
         let i = Intersection {
-            object: Object::Sphere(Sphere::new(&Material::default())),
+            object: Arc::new(Object::new(Sphere, Material::default())),
             distance: 4.0,
         };
         let comps = prepare_computations(&i, &r, &vec![i.clone()]);
@@ -432,9 +443,12 @@ mod tests {
                 y: 0.0,
                 z: 1.0,
             },
+            time: 0.0,
         };
-        let s = Object::Sphere(Sphere::new(&Material::default()))
-            .set_transform(Matrix44::translation(0.0, 0.0, 1.0));
+        let s = Arc::new(
+            Object::new(Sphere, Material::default())
+                .set_transform(Matrix44::translation(0.0, 0.0, 1.0)),
+        );
 
         let i = Intersection {
             object: s,
@@ -459,6 +473,7 @@ mod tests {
                 y: 0.0,
                 z: 1.0,
             },
+            time: 0.0,
         };
         let color = color_at(&world, &ray, 0);
         assert_eq!(
@@ -497,6 +512,7 @@ mod tests {
                 y: 0.0,
                 z: 1.0,
             },
+            time: 0.0,
         };
         let shape = &world.objects[1];
         let intersection = Intersection {
@@ -529,6 +545,7 @@ mod tests {
                 y: 1.0,
                 z: 0.0,
             },
+            time: 0.0,
         };
         let color = color_at(&world, &ray, 0);
         assert_eq!(
@@ -555,6 +572,7 @@ mod tests {
                 y: 0.0,
                 z: 1.0,
             },
+            time: 0.0,
         };
         let color = color_at(&world, &ray, 0);
         assert_eq!(
@@ -570,11 +588,11 @@ mod tests {
     #[test]
     fn test_intersection_hit_behind_ray() {
         let mut world = World::default();
-        world.objects[0].set_material(Material {
+        Arc::make_mut(&mut world.objects[0]).set_material(Material {
             ambient: 1.0,
             ..Default::default()
         });
-        world.objects[1].set_material(Material {
+        Arc::make_mut(&mut world.objects[1]).set_material(Material {
             ambient: 1.0,
             ..Default::default()
         });
@@ -590,6 +608,7 @@ mod tests {
                 y: 0.0,
                 z: -1.0,
             },
+            time: 0.0,
         };
         let color = color_at(&world, &ray, 0);
         assert_eq!(color, inner.material().color);
@@ -597,7 +616,7 @@ mod tests {
 
     #[test]
     fn test_precomputing_reflection_vector() {
-        let p = Object::Plane(Plane::new(Material::default()));
+        let p = Arc::new(Object::new(Plane, Material::default()));
         let ray = Ray {
             origin: Point {
                 x: 0.0,
@@ -609,6 +628,7 @@ mod tests {
                 y: -2.0_f64.sqrt() / 2.0,
                 z: 2.0_f64.sqrt() / 2.0,
             },
+            time: 0.0,
         };
         let intersection = Intersection {
             object: p.clone(),
@@ -639,8 +659,9 @@ mod tests {
                 y: 0.0,
                 z: 1.0,
             },
+            time: 0.0,
         };
-        world.objects[1].set_material(Material {
+        Arc::make_mut(&mut world.objects[1]).set_material(Material {
             ambient: 1.0,
             ..Material::default()
         });
@@ -656,11 +677,16 @@ mod tests {
     #[test]
     fn test_reflected_color_on_reflective_material() {
         let mut world = World::default();
-        let plane = Object::Plane(Plane::new(Material {
-            reflective: 0.5,
-            ..Material::default()
-        }))
-        .set_transform(Matrix44::translation(0.0, -1.0, 0.0));
+        let plane = Arc::new(
+            Object::new(
+                Plane,
+                Material {
+                    reflective: 0.5,
+                    ..Material::default()
+                },
+            )
+            .set_transform(Matrix44::translation(0.0, -1.0, 0.0)),
+        );
         world.objects.push(plane.clone());
         let ray = Ray {
             origin: Point {
@@ -673,6 +699,7 @@ mod tests {
                 y: -2.0_f64.sqrt() / 2.0,
                 z: 2.0_f64.sqrt() / 2.0,
             },
+            time: 0.0,
         };
         let intersection = Intersection {
             object: plane.clone(),
@@ -693,11 +720,16 @@ mod tests {
     #[test]
     fn test_shade_hit_on_reflective_material() {
         let mut world = World::default();
-        let plane = Object::Plane(Plane::new(Material {
-            reflective: 0.5,
-            ..Material::default()
-        }))
-        .set_transform(Matrix44::translation(0.0, -1.0, 0.0));
+        let plane = Arc::new(
+            Object::new(
+                Plane,
+                Material {
+                    reflective: 0.5,
+                    ..Material::default()
+                },
+            )
+            .set_transform(Matrix44::translation(0.0, -1.0, 0.0)),
+        );
         world.objects.push(plane.clone());
         let ray = Ray {
             origin: Point {
@@ -710,6 +742,7 @@ mod tests {
                 y: -2.0_f64.sqrt() / 2.0,
                 z: 2.0_f64.sqrt() / 2.0,
             },
+            time: 0.0,
         };
         let intersection = Intersection {
             object: plane.clone(),
@@ -727,6 +760,58 @@ mod tests {
         );
     }
 
+    // The reflective plane below the default world, seen at 45 degrees.
+    fn reflective_plane_hit(world: &mut World) -> Computations {
+        let plane = Arc::new(
+            Object::new(
+                Plane,
+                Material {
+                    reflective: 0.5,
+                    ..Material::default()
+                },
+            )
+            .set_transform(Matrix44::translation(0.0, -1.0, 0.0)),
+        );
+        world.objects.push(plane.clone());
+        let ray = Ray {
+            origin: Point {
+                x: 0.0,
+                y: 0.0,
+                z: -3.0,
+            },
+            direction: Vector3 {
+                x: 0.0,
+                y: -2.0_f64.sqrt() / 2.0,
+                z: 2.0_f64.sqrt() / 2.0,
+            },
+            time: 0.0,
+        };
+        let intersection = Intersection {
+            object: plane,
+            distance: 2.0_f64.sqrt(),
+        };
+        prepare_computations(&intersection, &ray, &vec![intersection.clone()])
+    }
+
+    #[test]
+    fn test_shade_hit_with_two_lights() {
+        let mut world = World::default();
+        let comps = reflective_plane_hit(&mut world);
+        let one_light = shade_hit(&world, &comps, 5);
+        world.lights.push(Light::PointLight(PointLight {
+            position: world.lights[0].position(),
+            color: world.lights[0].color(),
+        }));
+        let contributions = shade_hit_contributions(&world, &comps, 5);
+        // The reflection is added once and lights up with both lights, so doubling the light
+        // doubles the whole color.
+        assert_eq!(contributions.reflected, reflected_color(&world, &comps, 5));
+        let two_lights = shade_hit(&world, &comps, 5);
+        assert!((two_lights.red - 2.0 * one_light.red).abs() < 1e-5);
+        assert!((two_lights.green - 2.0 * one_light.green).abs() < 1e-5);
+        assert!((two_lights.blue - 2.0 * one_light.blue).abs() < 1e-5);
+    }
+
     #[test]
     fn test_color_at_with_mutually_reflective() {
         let light = Light::PointLight(PointLight {
@@ -737,15 +822,21 @@ mod tests {
             },
             color: Color::white(),
         });
-        let lower_plane = Object::Plane(Plane::new(Material {
-            reflective: 1.0,
-            ..Material::default()
-        }))
+        let lower_plane = Object::new(
+            Plane,
+            Material {
+                reflective: 1.0,
+                ..Material::default()
+            },
+        )
         .set_transform(Matrix44::translation(0.0, -1.0, 0.0));
-        let higher_plane = Object::Plane(Plane::new(Material {
-            reflective: 1.0,
-            ..Material::default()
-        }))
+        let higher_plane = Object::new(
+            Plane,
+            Material {
+                reflective: 1.0,
+                ..Material::default()
+            },
+        )
         .set_transform(Matrix44::translation(0.0, 1.0, 0.0));
         let world = World::new(vec![lower_plane.clone(), higher_plane.clone()], vec![light]);
         let ray = Ray {
@@ -759,6 +850,7 @@ mod tests {
                 y: 1.0,
                 z: 0.0,
             },
+            time: 0.0,
         };
         let _color_at = color_at(&world, &ray, 5);
     }
@@ -766,11 +858,16 @@ mod tests {
     #[test]
     fn test_recursive_maximum_depth() {
         let mut world = World::default();
-        let plane = Object::Plane(Plane::new(Material {
-            reflective: 0.5,
-            ..Material::default()
-        }))
-        .set_transform(Matrix44::translation(0.0, -1.0, 0.0));
+        let plane = Arc::new(
+            Object::new(
+                Plane,
+                Material {
+                    reflective: 0.5,
+                    ..Material::default()
+                },
+            )
+            .set_transform(Matrix44::translation(0.0, -1.0, 0.0)),
+        );
         world.objects.push(plane.clone());
         let ray = Ray {
             origin: Point {
@@ -783,6 +880,7 @@ mod tests {
                 y: -2.0_f64.sqrt() / 2.0,
                 z: 2.0_f64.sqrt() / 2.0,
             },
+            time: 0.0,
         };
         let intersection = Intersection {
             object: plane.clone(),
@@ -795,11 +893,13 @@ mod tests {
 
     #[test]
     fn test_n1_n2_multi_intersection() {
-        let a = Object::Sphere(Sphere::glass(1.5)).set_transform(Matrix44::scaling(2.0, 2.0, 2.0));
-        let b = Object::Sphere(Sphere::glass(2.0))
-            .set_transform(Matrix44::translation(0.0, 0.0, -0.25));
-        let c =
-            Object::Sphere(Sphere::glass(2.5)).set_transform(Matrix44::translation(0.0, 0.0, 0.25));
+        let a = Arc::new(Object::glass_sphere(1.5).set_transform(Matrix44::scaling(2.0, 2.0, 2.0)));
+        let b = Arc::new(
+            Object::glass_sphere(2.0).set_transform(Matrix44::translation(0.0, 0.0, -0.25)),
+        );
+        let c = Arc::new(
+            Object::glass_sphere(2.5).set_transform(Matrix44::translation(0.0, 0.0, 0.25)),
+        );
         let ray = Ray {
             origin: Point {
                 x: 0.0,
@@ -811,6 +911,7 @@ mod tests {
                 y: 0.0,
                 z: 1.0,
             },
+            time: 0.0,
         };
         let intersections = vec![
             Intersection {
@@ -872,6 +973,55 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_n1_n2_identical_objects() {
+        // Two equal spheres are still two different containers.
+        let a = Arc::new(Object::glass_sphere(1.5));
+        let b = Arc::new((*a).clone());
+        assert_eq!(a, b);
+        let ray = Ray {
+            origin: Point {
+                x: 0.0,
+                y: 0.0,
+                z: -4.0,
+            },
+            direction: Vector3 {
+                x: 0.0,
+                y: 0.0,
+                z: 1.0,
+            },
+            time: 0.0,
+        };
+        let intersections = vec![
+            Intersection {
+                object: a.clone(),
+                distance: 3.0,
+            },
+            Intersection {
+                object: b.clone(),
+                distance: 3.0,
+            },
+            Intersection {
+                object: a.clone(),
+                distance: 5.0,
+            },
+            Intersection {
+                object: b.clone(),
+                distance: 5.0,
+            },
+        ];
+        let comps = prepare_computations(&intersections[1], &ray, &intersections);
+        assert_eq!(comps.n1, 1.5);
+        assert_eq!(comps.n2, 1.5);
+        assert!(Arc::ptr_eq(&comps.object, &b));
+        let comps = prepare_computations(&intersections[2], &ray, &intersections);
+        assert_eq!(comps.n1, 1.5);
+        assert_eq!(comps.n2, 1.5);
+        let comps = prepare_computations(&intersections[3], &ray, &intersections);
+        assert_eq!(comps.n1, 1.5);
+        assert_eq!(comps.n2, 1.0);
+    }
+
     #[test]
     fn test_under_point_refraction() {
         let ray = Ray {
@@ -885,9 +1035,11 @@ mod tests {
                 y: 0.0,
                 z: 1.0,
             },
+            time: 0.0,
         };
-        let sphere =
-            Object::Sphere(Sphere::glass(1.52)).set_transform(Matrix44::translation(0.0, 0.0, 1.0));
+        let sphere = Arc::new(
+            Object::glass_sphere(1.52).set_transform(Matrix44::translation(0.0, 0.0, 1.0)),
+        );
         let i = Intersection {
             object: sphere.clone(),
             distance: 5.0,
@@ -912,6 +1064,7 @@ mod tests {
                 y: 0.0,
                 z: 1.0,
             },
+            time: 0.0,
         };
         let intersections = vec![
             Intersection {
@@ -931,7 +1084,7 @@ mod tests {
     #[test]
     fn test_refracted_color_max_depth() {
         let mut w = World::default();
-        w.objects[0].set_material(Material {
+        Arc::make_mut(&mut w.objects[0]).set_material(Material {
             transparency: 1.0,
             refractive_index: 1.5,
             ..Material::default()
@@ -948,6 +1101,7 @@ mod tests {
                 y: 0.0,
                 z: 1.0,
             },
+            time: 0.0,
         };
         let intersections = vec![
             Intersection {
@@ -967,7 +1121,7 @@ mod tests {
     #[test]
     fn test_total_internal_reflection() {
         let mut w = World::default();
-        w.objects[0].set_material(Material {
+        Arc::make_mut(&mut w.objects[0]).set_material(Material {
             transparency: 1.0,
             refractive_index: 1.5,
             ..Material::default()
@@ -984,6 +1138,7 @@ mod tests {
                 y: 1.0,
                 z: 0.0,
             },
+            time: 0.0,
         };
         let intersections = vec![
             Intersection {
@@ -1003,12 +1158,12 @@ mod tests {
     #[test]
     fn test_refracted_color_on_ray() {
         let mut w = World::default();
-        w.objects[0].set_material(Material {
+        Arc::make_mut(&mut w.objects[0]).set_material(Material {
             ambient: 1.0,
-            pattern: Some(Pattern::TestPattern),
+            pattern: Some(Pattern::new(TestPattern)),
             ..Material::default()
         });
-        w.objects[1].set_material(Material {
+        Arc::make_mut(&mut w.objects[1]).set_material(Material {
             transparency: 1.0,
             refractive_index: 1.5,
             ..Material::default()
@@ -1026,6 +1181,7 @@ mod tests {
                 y: 1.0,
                 z: 0.0,
             },
+            time: 0.0,
         };
         let intersections = vec![
             Intersection {
@@ -1060,23 +1216,33 @@ mod tests {
     #[test]
     fn test_shade_hit_on_transparent_material() {
         let mut w = World::default();
-        let floor = Object::Plane(Plane::new(Material {
-            transparency: 0.5,
-            refractive_index: 1.5,
-            ..Material::default()
-        }))
-        .set_transform(Matrix44::translation(0.0, -1.0, 0.0));
+        let floor = Arc::new(
+            Object::new(
+                Plane,
+                Material {
+                    transparency: 0.5,
+                    refractive_index: 1.5,
+                    ..Material::default()
+                },
+            )
+            .set_transform(Matrix44::translation(0.0, -1.0, 0.0)),
+        );
         w.objects.push(floor.clone());
-        let ball = Object::Sphere(Sphere::new(&Material {
-            ambient: 0.5,
-            color: Color {
-                red: 1.0,
-                green: 0.0,
-                blue: 0.0,
-            },
-            ..Material::default()
-        }))
-        .set_transform(Matrix44::translation(0.0, -3.5, -0.5));
+        let ball = Arc::new(
+            Object::new(
+                Sphere,
+                Material {
+                    ambient: 0.5,
+                    color: Color {
+                        red: 1.0,
+                        green: 0.0,
+                        blue: 0.0,
+                    },
+                    ..Material::default()
+                },
+            )
+            .set_transform(Matrix44::translation(0.0, -3.5, -0.5)),
+        );
         w.objects.push(ball);
         let ray = Ray {
             origin: Point {
@@ -1089,6 +1255,7 @@ mod tests {
                 y: -2.0_f64.sqrt() / 2.0,
                 z: 2.0_f64.sqrt() / 2.0,
             },
+            time: 0.0,
         };
         let intersections = vec![Intersection {
             object: floor.clone(),
@@ -1108,7 +1275,7 @@ mod tests {
 
     #[test]
     fn test_reflectance_total_internal_reflection() {
-        let sphere = Object::Sphere(Sphere::glass(1.52));
+        let sphere = Arc::new(Object::glass_sphere(1.52));
         let ray = Ray {
             origin: Point {
                 x: 0.0,
@@ -1120,6 +1287,7 @@ mod tests {
                 y: 1.0,
                 z: 0.0,
             },
+            time: 0.0,
         };
         let intersections = vec![
             Intersection {
@@ -1138,7 +1306,7 @@ mod tests {
 
     #[test]
     fn test_reflectance_90_degrees_on_surface() {
-        let sphere = Object::Sphere(Sphere::glass(1.52));
+        let sphere = Arc::new(Object::glass_sphere(1.52));
         let ray = Ray {
             origin: Point {
                 x: 0.0,
@@ -1150,6 +1318,7 @@ mod tests {
                 y: 1.0,
                 z: 0.0,
             },
+            time: 0.0,
         };
         let intersections = vec![
             Intersection {
@@ -1168,7 +1337,7 @@ mod tests {
 
     #[test]
     fn test_reflectance_n2_greater_n1() {
-        let sphere = Object::Sphere(Sphere::glass(1.52));
+        let sphere = Arc::new(Object::glass_sphere(1.52));
         let ray = Ray {
             origin: Point {
                 x: 0.0,
@@ -1180,6 +1349,7 @@ mod tests {
                 y: 0.0,
                 z: 1.0,
             },
+            time: 0.0,
         };
         let intersections = vec![Intersection {
             object: sphere.clone(),
@@ -1193,24 +1363,34 @@ mod tests {
     #[test]
     fn test_reflectance_used_in_shade_hit() {
         let mut w = World::default();
-        let floor = Object::Plane(Plane::new(Material {
-            reflective: 0.5,
-            transparency: 0.5,
-            refractive_index: 1.5,
-            ..Material::default()
-        }))
-        .set_transform(Matrix44::translation(0.0, -1.0, 0.0));
+        let floor = Arc::new(
+            Object::new(
+                Plane,
+                Material {
+                    reflective: 0.5,
+                    transparency: 0.5,
+                    refractive_index: 1.5,
+                    ..Material::default()
+                },
+            )
+            .set_transform(Matrix44::translation(0.0, -1.0, 0.0)),
+        );
         w.objects.push(floor.clone());
-        let ball = Object::Sphere(Sphere::new(&Material {
-            ambient: 0.5,
-            color: Color {
-                red: 1.0,
-                green: 0.0,
-                blue: 0.0,
-            },
-            ..Material::default()
-        }))
-        .set_transform(Matrix44::translation(0.0, -3.5, -0.5));
+        let ball = Arc::new(
+            Object::new(
+                Sphere,
+                Material {
+                    ambient: 0.5,
+                    color: Color {
+                        red: 1.0,
+                        green: 0.0,
+                        blue: 0.0,
+                    },
+                    ..Material::default()
+                },
+            )
+            .set_transform(Matrix44::translation(0.0, -3.5, -0.5)),
+        );
         w.objects.push(ball);
         let ray = Ray {
             origin: Point {
@@ -1223,6 +1403,7 @@ mod tests {
                 y: -2.0_f64.sqrt() / 2.0,
                 z: 2.0_f64.sqrt() / 2.0,
             },
+            time: 0.0,
         };
         let intersections = vec![Intersection {
             object: floor.clone(),
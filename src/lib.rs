@@ -0,0 +1,42 @@
+// Ray tracer following "The Ray Tracer Challenge", built into the `rustracer` exporter and the
+// `viewer` preview window.
+pub mod animation;
+pub mod aov;
+pub mod bounds;
+pub mod camera;
+pub mod cli;
+pub mod color;
+pub mod debug_view;
+pub mod demo;
+pub mod denoise;
+pub mod framebuffer;
+pub mod image_diff;
+pub mod inspect;
+pub mod intersection;
+pub mod light;
+pub mod material;
+pub mod matrix;
+pub mod motion;
+pub mod navigation;
+pub mod objects;
+pub mod patterns;
+pub mod point;
+pub mod preview;
+pub mod quaternion;
+pub mod ray;
+pub mod render;
+pub mod rig;
+pub mod sampler;
+pub mod scene;
+pub mod stats;
+pub mod tonemap;
+pub mod vector3;
+pub mod world;
+
+pub use camera::Camera;
+pub use light::Light;
+pub use material::Material;
+pub use objects::{Object, Shape};
+pub use patterns::{Pattern, PatternFn};
+pub use render::{render, RenderSettings};
+pub use world::World;
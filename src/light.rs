@@ -4,6 +4,7 @@ use crate::material::*;
 use crate::objects::*;
 use crate::point::Point;
 use crate::ray::*;
+use crate::stats::{self, RayCategory};
 use crate::vector3::*;
 use crate::world::*;
 
@@ -28,6 +29,12 @@ impl Light {
             Light::PointLight(light) => light.color,
         }
     }
+
+    pub fn set_position(&mut self, position: Point) {
+        match self {
+            Light::PointLight(light) => light.position = position,
+        }
+    }
 }
 
 pub fn lighting(
@@ -39,15 +46,10 @@ pub fn lighting(
     normal_vector: &Vector3,
     in_shadow: bool,
 ) -> Color {
-    let mut material_color = material.color;
     let diffuse: Color;
     let specular: Color;
 
-    match &material.pattern {
-        Some(x) => material_color = x.pattern_at_object(hit_point, object),
-        None => {}
-    }
-    let effective_color = material_color * light.color();
+    let effective_color = material.color_at(object, hit_point) * light.color();
     let lightv = (light.position() - *hit_point).normalize();
     let ambient = effective_color * material.ambient;
     let light_dot_normal = lightv.dot(normal_vector);
@@ -71,14 +73,16 @@ pub fn lighting(
     ambient + diffuse + specular
 }
 
-pub fn is_shadowed(world: &World, point: &Point, light: &Light) -> bool {
+pub fn is_shadowed(world: &World, point: &Point, light: &Light, time: f64) -> bool {
     let v = light.position() - *point;
     let distance = v.length();
     let direction = v.normalize();
     let ray = Ray {
         origin: point.clone(),
         direction,
+        time,
     };
+    stats::ray(RayCategory::Shadow);
     let intersections = intersect_world(&ray, world);
     match hit(intersections) {
         Some(x) => {
@@ -27,7 +27,7 @@ mod tests {
             },
             color: Color::default(),
         });
-        let sphere = Object::Sphere(Sphere::new(&Material::default()));
+        let sphere = Object::new(Sphere, Material::default());
         let res = lighting(
             &Material::default(),
             &sphere,
@@ -67,7 +67,7 @@ mod tests {
             },
             color: Color::default(),
         });
-        let sphere = Object::Sphere(Sphere::new(&Material::default()));
+        let sphere = Object::new(Sphere, Material::default());
         let res = lighting(
             &Material::default(),
             &sphere,
@@ -107,7 +107,7 @@ mod tests {
             },
             color: Color::default(),
         });
-        let sphere = Object::Sphere(Sphere::new(&Material::default()));
+        let sphere = Object::new(Sphere, Material::default());
         let res = lighting(
             &Material::default(),
             &sphere,
@@ -148,7 +148,7 @@ mod tests {
             color: Color::default(),
         });
         let in_shadow = true;
-        let sphere = Object::Sphere(Sphere::new(&Material::default()));
+        let sphere = Object::new(Sphere, Material::default());
         let result = lighting(
             &Material::default(),
             &sphere,
@@ -176,7 +176,7 @@ mod tests {
             y: 10.0,
             z: 0.0,
         };
-        assert_eq!(is_shadowed(&world, &p, &world.lights[0]), false);
+        assert_eq!(is_shadowed(&world, &p, &world.lights[0], 0.0), false);
     }
 
     #[test]
@@ -187,7 +187,7 @@ mod tests {
             y: -10.0,
             z: 10.0,
         };
-        assert_eq!(is_shadowed(&world, &p, &world.lights[0]), true);
+        assert_eq!(is_shadowed(&world, &p, &world.lights[0], 0.0), true);
     }
 
     #[test]
@@ -198,7 +198,7 @@ mod tests {
             y: 20.0,
             z: -20.0,
         };
-        assert_eq!(is_shadowed(&world, &p, &world.lights[0]), false);
+        assert_eq!(is_shadowed(&world, &p, &world.lights[0], 0.0), false);
     }
 
     #[test]
@@ -209,7 +209,7 @@ mod tests {
             y: 2.0,
             z: -2.0,
         };
-        assert_eq!(is_shadowed(&world, &p, &world.lights[0]), false);
+        assert_eq!(is_shadowed(&world, &p, &world.lights[0], 0.0), false);
     }
 
     #[test]
@@ -218,9 +218,9 @@ mod tests {
         m.ambient = 1.0;
         m.diffuse = 0.0;
         m.specular = 0.0;
-        m.pattern = Some(Pattern::Stripe(Stripe::new(
-            Pattern::SolidColor(SolidColor::new(Color::white())),
-            Pattern::SolidColor(SolidColor::new(Color::black())),
+        m.pattern = Some(Pattern::new(Stripe::new(
+            Pattern::new(SolidColor::new(Color::white())),
+            Pattern::new(SolidColor::new(Color::black())),
         )));
         let eyev = Vector3 {
             x: 0.0,
@@ -240,7 +240,7 @@ mod tests {
             },
             color: Color::white(),
         });
-        let sphere = Object::Sphere(Sphere::new(&Material::default()));
+        let sphere = Object::new(Sphere, Material::default());
         let c1 = lighting(
             &m,
             &sphere,
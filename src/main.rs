@@ -1,315 +1,269 @@
-use image::{DynamicImage, GenericImage};
-use patterns::Pattern;
-use pixels::{Error, Pixels, SurfaceTexture};
+use image::codecs::gif::{GifEncoder, Repeat};
+use image::{Delay, Frame};
+use rustracer::animation::*;
+use rustracer::aov::*;
+use rustracer::camera::*;
+use rustracer::cli::*;
+use rustracer::debug_view::*;
+use rustracer::demo::*;
+use rustracer::framebuffer::*;
+use rustracer::image_diff::*;
+use rustracer::render::*;
+use rustracer::rig::*;
+use rustracer::stats;
+use rustracer::tonemap::*;
+use rustracer::world::*;
 use std::env;
-use winit::dpi::LogicalSize;
-use winit::event::{Event, VirtualKeyCode};
-use winit::event_loop::{ControlFlow, EventLoop};
-use winit::window::WindowBuilder;
-use winit_input_helper::WinitInputHelper;
-mod camera;
-mod color;
-mod intersection;
-mod light;
-mod material;
-mod matrix;
-mod objects;
-mod patterns;
-mod point;
-mod ray;
-mod vector3;
-mod world;
+use std::fs::File;
+use std::ops::RangeInclusive;
 
-use crate::camera::*;
-use crate::color::*;
-use crate::intersection::*;
-use crate::light::*;
-use crate::material::*;
-use crate::matrix::*;
-use crate::objects::cube::*;
-use crate::objects::plane::*;
-use crate::objects::sphere::*;
-use crate::objects::*;
-use crate::patterns::blend::*;
-use crate::patterns::checker::*;
-use crate::patterns::gradient::*;
-use crate::patterns::perturbed::*;
-use crate::patterns::radial_gradient::*;
-use crate::patterns::ring::*;
-use crate::patterns::solid_color::*;
-use crate::patterns::stripe::*;
-use crate::point::*;
-use crate::vector3::*;
-use crate::world::*;
-
-const WIDTH: u32 = 400;
-const HEIGHT: u32 = 400;
-// const WIDTH: u32 = 1200;
-// const HEIGHT: u32 = 800;
-// const WIDTH: u32 = 2560;
-// const HEIGHT: u32 = 1440;
-const MAX_RECURSION: i32 = 5;
-
-fn get_scene() -> (Camera, World) {
-    let middlesphere = Object::Sphere(Sphere::new(&Material {
-        color: Color {
-            red: 0.1,
-            green: 1.0,
-            blue: 0.5,
-        },
-        diffuse: 1.0,
-        specular: 0.0,
-        // refractive_index: 1.59,
-        // transparency: 1.0,
-        pattern: Some(
-            Pattern::Checker(Checker::new(
-                Pattern::SolidColor(SolidColor::new(Color::cyan())),
-                Pattern::SolidColor(SolidColor::new(Color::cyan() - 0.08)),
-            ))
-            .set_transform(
-                Matrix44::scaling(0.5, 0.5, 0.5)
-                    .rotate_z(std::f64::consts::FRAC_PI_6)
-                    .rotate_x(-std::f64::consts::FRAC_PI_6),
-            ),
-        ),
-        ..Material::default()
-    }))
-    .set_transform(Matrix44::scaling(2.0, 2.0, 2.0).translate(-0.5, 2.0, 3.5));
-    let rightsphere = Object::Sphere(Sphere::new(&Material {
-        color: Color {
-            red: 0.5,
-            green: 1.0,
-            blue: 0.1,
-        },
-        diffuse: 0.7,
-        specular: 0.3,
-        reflective: 0.3,
-        pattern: Some(Pattern::Perturbed(Perturbed::new(
-            Pattern::Ring(Ring::new(
-                Pattern::SolidColor(SolidColor::new(Color::yellow() + 0.2)),
-                Pattern::SolidColor(SolidColor::new(Color::orange())),
-            ))
-            .set_transform(Matrix44::rotation_x(std::f64::consts::FRAC_PI_2).scale(0.1, 0.1, 0.1)),
-            0.4,
-        ))),
-        ..Material::default()
-    }))
-    .set_transform(Matrix44::scaling(0.5, 0.5, 0.5).translate(1.5, 0.5, -0.5));
-    let rightsphere_2 = Object::Sphere(Sphere::new(&Material {
-        color: Color {
-            red: 0.4,
-            green: 0.4,
-            blue: 0.4,
-        },
-        diffuse: 0.1,
-        refractive_index: 1.59,
-        transparency: 0.9,
-        specular: 1.0,
-        shininess: 300.0,
-        ..Material::default()
-    }))
-    .set_transform(Matrix44::scaling(0.3, 0.3, 0.3).translate(0.0, 0.3, -1.5));
-    let leftsphere = Object::Sphere(Sphere::new(&Material {
-        color: Color {
-            red: 1.0,
-            green: 0.8,
-            blue: 0.1,
-        },
-        diffuse: 0.7,
-        specular: 0.8,
-        pattern: Some(
-            Pattern::RadialGradient(RadialGradient::new(
-                Pattern::SolidColor(SolidColor::new(Color::green() + 0.56)),
-                Pattern::SolidColor(SolidColor::new(Color::green() + 0.23)),
-            ))
-            .set_transform(Matrix44::rotation_x(-std::f64::consts::FRAC_PI_4).scale(0.2, 0.2, 0.2)),
-        ),
-        ..Material::default()
-    }))
-    .set_transform(Matrix44::scaling(0.33, 0.33, 0.33).translate(-1.5, 0.33, -0.75));
-    let plane = Object::Plane(Plane::new(Material {
-        specular: 0.0,
-        diffuse: 0.5,
-        ambient: 0.01,
-        reflective: 0.05,
-        color: Color {
-            red: 0.0,
-            green: 0.9,
-            blue: 0.9,
-        },
-        pattern: Some(Pattern::Blend(Blend::new(
-            Pattern::Stripe(Stripe::new(
-                Pattern::SolidColor(SolidColor::new(Color::gray())),
-                Pattern::SolidColor(SolidColor::new(Color::gray() + 0.08)),
-            ))
-            .set_transform(Matrix44::scaling(0.2, 0.2, 0.2).rotate_y(std::f64::consts::FRAC_PI_4)),
-            Pattern::Stripe(Stripe::new(
-                Pattern::SolidColor(SolidColor::new(Color::braun() + 0.2)),
-                Pattern::SolidColor(SolidColor::new(Color::braun() + 0.1)),
-            )),
-            0.7,
-        ))),
-        // pattern: Some(Pattern::Checker(Checker::new(
-        //     Pattern::Stripe(Stripe::new(
-        //         Pattern::SolidColor(SolidColor::new(Color::green() - 0.2)),
-        //         Pattern::SolidColor(SolidColor::new(Color::green() + 0.08)),
-        //     ))
-        //     .set_transform(Matrix44::scaling(0.2, 0.2, 0.2).rotate_y(std::f64::consts::FRAC_PI_4)),
-        //     Pattern::Stripe(Stripe::new(
-        //         Pattern::SolidColor(SolidColor::new(Color::yellow() - 0.4)),
-        //         Pattern::SolidColor(SolidColor::new(Color::yellow() - 0.2)),
-        //     ))
-        //     .set_transform(Matrix44::scaling(0.2, 0.2, 0.2).rotate_y(-std::f64::consts::FRAC_PI_4)),
-        // ))),
-        ..Material::default()
-    }));
-    let cube = Object::Cube(Cube::new(&Material {
-        color: Color {
-            red: 1.0,
-            green: 0.3,
-            blue: 0.5,
-        },
-        diffuse: 0.7,
-        specular: 0.8,
-        reflective: 0.3,
-        pattern: Some(
-            Pattern::Stripe(Stripe::new(
-                Pattern::SolidColor(SolidColor::new(Color::red())),
-                Pattern::SolidColor(SolidColor::new(Color::red() + 0.28)),
-            ))
-            .set_transform(Matrix44::scaling(0.2, 0.2, 0.2).rotate_y(std::f64::consts::FRAC_PI_4)),
-        ),
-        ..Material::default()
-    }))
-    .set_transform(Matrix44::scaling(0.1, 0.1, 0.1).translate(1.0, 0.1, -2.0));
+// Headless renderer, writes render.png or the files asked for by `--frames`, `--stereo`,
+// `--cubemap` and `--aov`, then prints render statistics, also saved as render_stats.json with
+// `--stats-json`. `rustracer diff a.png b.png` compares two images instead. The interactive preview
+// is the `viewer` binary.
+fn main() {
+    let args: Vec<String> = env::args().collect();
+    if args.get(1).map(|a| a.as_str()) == Some("diff") {
+        compare_images(&args[2..]);
+        return;
+    }
+    stats::count_intersection_tests();
+    let scene = || stats::phase("scene", || scene_from_args(&args));
+    let (cam, world) = scene();
+    let settings = render_settings(&args);
+    // png, or hdr, pfm and exr to keep the floating point values.
+    let format = arg_value(&args, "--format").unwrap_or("png");
+    if let Some(frames) = arg_value(&args, "--frames") {
+        let (first, last) = frames
+            .split_once(':')
+            .map(|(a, b)| (a.parse(), b.parse()))
+            .and_then(|(a, b)| Some((a.ok()?, b.ok()?)))
+            .expect("--frames expects first:last");
+        let fps = arg_value(&args, "--fps")
+            .map(|v| {
+                v.parse()
+                    .ok()
+                    .filter(|fps| *fps > 0)
+                    .expect("--fps expects a positive number")
+            })
+            .unwrap_or(24);
+        let gif = args.iter().any(|a| a == "--gif");
+        draw_sequence(
+            &get_animation(),
+            &scene,
+            first..=last,
+            fps,
+            gif,
+            &settings,
+            format,
+        );
+    } else if let Some(layout) = arg_value(&args, "--stereo") {
+        let rig = StereoRig {
+            interocular: arg_value(&args, "--iod")
+                .map(|v| v.parse().expect("--iod expects a number"))
+                .unwrap_or(StereoRig::default().interocular),
+            convergence: arg_value(&args, "--convergence")
+                .map(|v| v.parse().expect("--convergence expects a number"))
+                .unwrap_or(StereoRig::default().convergence),
+            layout: match layout {
+                "side-by-side" => StereoLayout::SideBySide,
+                "over-under" => StereoLayout::OverUnder,
+                _ => panic!("--stereo expects side-by-side or over-under"),
+            },
+        };
+        draw_stereo_image(&cam, &world, &rig, &settings, format);
+    } else if args.iter().any(|a| a == "--cubemap") {
+        let size = arg_value(&args, "--cubemap")
+            .and_then(|v| v.parse().ok())
+            .unwrap_or(HEIGHT);
+        draw_cubemap_images(
+            &CubemapRig::new(cam.position(), size),
+            &world,
+            &settings,
+            format,
+        );
+    } else if let Some(names) = arg_value(&args, "--aov") {
+        // Passes split the shaded color, debug views have none to split.
+        if settings.view != DebugView::Beauty {
+            panic!("--aov only renders the beauty view, drop --view or --aov");
+        }
+        let passes: Vec<Aov> = match names {
+            "all" => Aov::all().to_vec(),
+            _ => names
+                .split(',')
+                .map(|n| Aov::from_name(n).unwrap_or_else(|| panic!("unknown pass {}", n)))
+                .collect(),
+        };
+        draw_image_with_aovs(&cam, &world, &passes, &settings, format);
+    } else {
+        draw_image(&cam, &world, &settings, format);
+    }
+    let stats = stats::take();
+    println!("{}", stats);
+    if args.iter().any(|a| a == "--stats-json") {
+        std::fs::write("render_stats.json", stats.to_json()).unwrap();
+    }
+}
 
-    let world = World {
-        objects: vec![
-            cube,
-            plane,
-            middlesphere,
-            rightsphere,
-            leftsphere,
-            rightsphere_2,
-        ],
-        lights: vec![
-            Light::PointLight(PointLight {
-                position: Point {
-                    x: -10.0,
-                    y: 10.0,
-                    z: -10.0,
-                },
-                color: Color::white(),
-            }),
-            // Light::PointLight(PointLight {
-            //     position: Point {
-            //         x: 10.0,
-            //         y: 3.0,
-            //         z: -30.0,
-            //     },
-            //     color: Color {
-            //         red: 0.7,
-            //         green: 0.7,
-            //         blue: 0.7,
-            //     },
-            // }),
-        ],
-    };
-    let mut cam = Camera::new(WIDTH, HEIGHT, std::f64::consts::PI / 3.0);
-    cam.transform = view_transform(
-        Point {
-            x: 0.0,
-            // y: 2.5,
-            // z: -10.0,
-            y: 1.5,
-            z: -5.0,
-        },
-        Point {
-            x: 0.0,
-            y: 1.0,
-            z: 0.0,
-        },
-        Vector3 {
-            x: 0.0,
-            y: 1.0,
-            z: 0.0,
-        },
+fn draw_image(cam: &Camera, world: &World, settings: &RenderSettings, format: &str) {
+    let framebuffer = render_timed(cam, world, settings);
+    save(
+        &framebuffer,
+        format!("render.{}", format),
+        &settings.display(),
     );
-    return (cam, world);
 }
 
-fn main() -> Result<(), Error> {
-    let (cam, world) = get_scene();
-    let args: Vec<String> = env::args().collect();
-    if args.len() > 1 && args[1] == "--export" {
-        draw_image(&cam, &world);
-        return Result::Ok(());
-    }
-    let mut input = WinitInputHelper::new();
-    let event_loop = EventLoop::new();
-    let window = {
-        let size = LogicalSize::new(WIDTH as f64, HEIGHT as f64);
-        let scaled_size = LogicalSize::new(WIDTH as f64, HEIGHT as f64);
-        WindowBuilder::new()
-            .with_title("Rustracer")
-            .with_inner_size(scaled_size)
-            .with_min_inner_size(size)
-            .build(&event_loop)
-            .unwrap()
-    };
-    let window_size = window.inner_size();
-    let surface_texture = SurfaceTexture::new(window_size.width, window_size.height, &window);
-    let mut pixels = Pixels::new(WIDTH, HEIGHT, surface_texture)?;
-    event_loop.run(move |event, _, control_flow| {
-        if let Event::RedrawRequested(_) = event {
-            draw_frame(pixels.get_frame(), &cam, &world);
-            if pixels.render().is_err() {
-                *control_flow = ControlFlow::Exit;
-                return;
-            }
-        }
-        if input.update(&event) {
-            if input.key_released(VirtualKeyCode::Escape) || input.quit() {
-                *control_flow = ControlFlow::Exit;
-                return;
-            }
-            // window.request_redraw();
+// Saves render.png along with render_depth.png, render_normal.png... for each requested pass.
+fn draw_image_with_aovs(
+    cam: &Camera,
+    world: &World,
+    passes: &[Aov],
+    settings: &RenderSettings,
+    format: &str,
+) {
+    let (aovs, framebuffer) = stats::phase("render", || {
+        let aovs = render_aovs(cam, world, settings);
+        let framebuffer = beauty(&aovs, settings);
+        (aovs, framebuffer)
+    });
+    save(
+        &framebuffer,
+        format!("render.{}", format),
+        &settings.display(),
+    );
+    stats::phase("save", || {
+        for aov in passes {
+            aovs.save(
+                *aov,
+                format!("render_{}.{}", aov.name(), format),
+                &settings.post_process,
+            )
+            .unwrap();
         }
     });
 }
 
-fn draw_frame(frame: &mut [u8], cam: &Camera, world: &World) {
-    for (i, pixel) in frame.chunks_exact_mut(4).enumerate() {
-        let x = (i % WIDTH as usize) as u32;
-        let y = (i / WIDTH as usize) as u32;
-        let ray = cam.ray_for_pixel(x, y);
-        let color = color_at(&world, &ray, MAX_RECURSION);
-        pixel.copy_from_slice(&[
-            (color.red * 255.0) as u8,
-            (color.green * 255.0) as u8,
-            (color.blue * 255.0) as u8,
-            0xff,
-        ]);
+fn draw_stereo_image(
+    cam: &Camera,
+    world: &World,
+    rig: &StereoRig,
+    settings: &RenderSettings,
+    format: &str,
+) {
+    let (left, right) = rig.eyes(cam);
+    let (width, height) = rig.layout.dimensions(cam.hsize, cam.vsize);
+    let (x, y) = rig.layout.right_eye_offset(cam.hsize, cam.vsize);
+    let mut framebuffer = Framebuffer::new(width, height);
+    framebuffer.copy_from(&render_timed(&left, world, settings), 0, 0);
+    framebuffer.copy_from(&render_timed(&right, world, settings), x, y);
+    save(
+        &framebuffer,
+        format!("render_stereo.{}", format),
+        &settings.display(),
+    );
+}
+
+fn draw_cubemap_images(rig: &CubemapRig, world: &World, settings: &RenderSettings, format: &str) {
+    for (face, cam) in rig.faces() {
+        save(
+            &render_timed(&cam, world, settings),
+            format!("render_{}.{}", face.name(), format),
+            &settings.display(),
+        );
     }
 }
 
-fn draw_image(cam: &Camera, world: &World) {
-    let mut img = DynamicImage::new_rgb8(WIDTH, HEIGHT);
-    for x in 0..WIDTH {
-        for y in 0..HEIGHT {
-            let ray = cam.ray_for_pixel(x, y);
-            let color = color_at(&world, &ray, MAX_RECURSION);
-            img.put_pixel(
-                x,
-                y,
-                image::Rgba([
-                    (color.red * 255.0) as u8,
-                    (color.green * 255.0) as u8,
-                    (color.blue * 255.0) as u8,
-                    255,
-                ]),
-            );
+// Renders the frames of the scene as render_0000.png, render_0001.png... and optionally render.gif
+fn draw_sequence(
+    animation: &Animation,
+    scene: &dyn Fn() -> (Camera, World),
+    frames: RangeInclusive<u32>,
+    fps: u32,
+    gif: bool,
+    settings: &RenderSettings,
+    format: &str,
+) {
+    let mut gif_frames = vec![];
+    for frame in frames {
+        let (mut cam, mut world) = scene();
+        let time = frame as f64 / fps as f64;
+        // Moving objects only blur when several samples are spread over the shutter.
+        let shutter = if settings.samples > 1 {
+            1.0 / fps as f64
+        } else {
+            0.0
+        };
+        animation.apply(&mut cam, &mut world, time, shutter);
+        let framebuffer = render_timed(&cam, &world, settings);
+        save(
+            &framebuffer,
+            format!("render_{:04}.{}", frame, format),
+            &settings.display(),
+        );
+        if gif {
+            gif_frames.push(Frame::from_parts(
+                settings.display().to_rgba8(&framebuffer),
+                0,
+                0,
+                Delay::from_numer_denom_ms(1000, fps),
+            ));
         }
     }
-    img.save("render.png").unwrap();
+    if gif {
+        stats::phase("save", || {
+            let mut encoder = GifEncoder::new(File::create("render.gif").unwrap());
+            encoder.set_repeat(Repeat::Infinite).unwrap();
+            encoder.encode_frames(gif_frames).unwrap();
+        });
+    }
+}
+
+// Renders and saves go through these so their time shows up in the statistics.
+fn render_timed(cam: &Camera, world: &World, settings: &RenderSettings) -> Framebuffer {
+    stats::phase("render", || render(cam, world, settings))
+}
+
+fn save(framebuffer: &Framebuffer, path: String, post_process: &PostProcess) {
+    stats::phase("save", || framebuffer.save(path, post_process).unwrap());
+}
+
+// Prints how far two images are apart and saves a heatmap of the differences as diff.png, or
+// where `--output` says. `--threshold` is the channel difference below which pixels count as the
+// same, and differences of `--scale` and up are white in the heatmap.
+fn compare_images(args: &[String]) {
+    let usage =
+        "usage: rustracer diff a.png b.png [--output diff.png] [--threshold 0] [--scale 32]";
+    let open = |path: &String| {
+        image::open(path)
+            .unwrap_or_else(|e| panic!("{}: {}", path, e))
+            .to_rgba8()
+    };
+    let (a, b) = match args {
+        [a, b, ..] if !a.starts_with("--") && !b.starts_with("--") => (a, b),
+        _ => panic!("{}", usage),
+    };
+    let diff = ImageDiff::new(&open(a), &open(b)).unwrap_or_else(|e| panic!("{}", e));
+    let threshold = arg_value(args, "--threshold")
+        .map(|v| {
+            v.parse()
+                .expect("--threshold expects a number from 0 to 255")
+        })
+        .unwrap_or(0);
+    let scale = arg_value(args, "--scale")
+        .map(|v| v.parse().expect("--scale expects a number from 1 to 255"))
+        .unwrap_or(Tolerance::default().max);
+    let output = arg_value(args, "--output").unwrap_or("diff.png");
+    let differing = diff.differing_pixels(threshold);
+    println!("size {:>30}", format!("{}x{}", diff.width, diff.height));
+    println!("rmse {:>30.4}", diff.rmse());
+    println!("psnr {:>27.2} dB", diff.psnr());
+    println!("max channel error {:>17}", diff.max_channel_error());
+    println!(
+        "differing pixels {:>18} ({:.2}%)",
+        differing,
+        100.0 * differing as f64 / diff.errors.len().max(1) as f64
+    );
+    diff.heatmap(scale).save(output).unwrap();
+    println!("heatmap {:>27}", output);
 }
@@ -1,3 +1,5 @@
+use crate::objects::Object;
+use crate::point::Point;
 use crate::{color::*, patterns::Pattern};
 
 #[derive(Clone, Debug)]
@@ -29,6 +31,16 @@ impl Default for Material {
     }
 }
 
+impl Material {
+    // Surface color at a point of the object, taken from the pattern when there is one.
+    pub fn color_at(&self, object: &Object, point: &Point) -> Color {
+        match &self.pattern {
+            Some(pattern) => pattern.pattern_at_object(point, object),
+            None => self.color,
+        }
+    }
+}
+
 impl PartialEq for Material {
     fn eq(&self, other: &Self) -> bool {
         self.color == other.color
@@ -0,0 +1,86 @@
+use crate::matrix::*;
+use crate::quaternion::*;
+use crate::vector3::*;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Interpolation {
+    // Element-wise blend of the two matrices, fine for translations.
+    Linear,
+    // Translation and scale are blended, rotation follows a slerp, so spinning objects keep their shape.
+    Quaternion,
+}
+
+// End of shutter transform of a moving object or camera, the start being its regular transform.
+// Time 0.0 maps to the start transform and time 1.0 to the end transform.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Motion {
+    pub end: Matrix44,
+    pub interpolation: Interpolation,
+}
+
+impl Motion {
+    pub fn new(end: Matrix44, interpolation: Interpolation) -> Self {
+        Motion { end, interpolation }
+    }
+
+    pub fn transform_at(&self, start: &Matrix44, time: f64) -> Matrix44 {
+        let t = time.clamp(0.0, 1.0);
+        match self.interpolation {
+            Interpolation::Linear => {
+                let mut result = Matrix44::default();
+                for i in 0..=3 {
+                    for j in 0..=3 {
+                        result.elements[i][j] =
+                            start.elements[i][j] * (1.0 - t) + self.end.elements[i][j] * t;
+                    }
+                }
+                result
+            }
+            Interpolation::Quaternion => {
+                let (t0, r0, s0) = decompose(start);
+                let (t1, r1, s1) = decompose(&self.end);
+                let translation = t0 * (1.0 - t) + t1 * t;
+                let scale = s0 * (1.0 - t) + s1 * t;
+                let rotation = r0.slerp(&r1, t);
+                Matrix44::translation(translation.x, translation.y, translation.z)
+                    * rotation.to_rotation()
+                    * Matrix44::scaling(scale.x, scale.y, scale.z)
+            }
+        }
+    }
+}
+
+// Splits an affine transform without shearing into translation, rotation and scale, as T * R * S.
+pub fn decompose(m: &Matrix44) -> (Vector3, Quaternion, Vector3) {
+    let e = &m.elements;
+    let translation = Vector3 {
+        x: e[0][3],
+        y: e[1][3],
+        z: e[2][3],
+    };
+    let column = |j: usize| Vector3 {
+        x: e[0][j],
+        y: e[1][j],
+        z: e[2][j],
+    };
+    let mut scale = Vector3 {
+        x: column(0).length(),
+        y: column(1).length(),
+        z: column(2).length(),
+    };
+    // A mirrored basis is kept as a negative scale on x so the rest stays a proper rotation.
+    if column(0).cross(&column(1)).dot(&column(2)) < 0.0 {
+        scale.x = -scale.x;
+    }
+    let mut rotation = Matrix44::identity();
+    for (row, source) in rotation.elements.iter_mut().zip(e.iter()).take(3) {
+        row[0] = source[0] / scale.x;
+        row[1] = source[1] / scale.y;
+        row[2] = source[2] / scale.z;
+    }
+    (translation, Quaternion::from_rotation(&rotation), scale)
+}
+
+#[cfg(test)]
+#[path = "./motion_tests.rs"]
+mod motion_tests;
@@ -0,0 +1,64 @@
+#[cfg(test)]
+mod tests {
+    use crate::motion::*;
+    use crate::point::*;
+
+    fn assert_close(a: &Matrix44, b: &Matrix44) {
+        for i in 0..=3 {
+            for j in 0..=3 {
+                assert!(
+                    (a.elements[i][j] - b.elements[i][j]).abs() < 1e-12,
+                    "{} != {}",
+                    a,
+                    b
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_linear_motion() {
+        let start = Matrix44::translation(0.0, 0.0, 0.0);
+        let motion = Motion::new(Matrix44::translation(2.0, 4.0, 0.0), Interpolation::Linear);
+        assert_close(&motion.transform_at(&start, 0.0), &start);
+        assert_close(
+            &motion.transform_at(&start, 0.5),
+            &Matrix44::translation(1.0, 2.0, 0.0),
+        );
+        assert_close(&motion.transform_at(&start, 1.0), &motion.end);
+    }
+
+    #[test]
+    fn test_decompose() {
+        let m = Matrix44::scaling(1.0, 2.0, 3.0)
+            .rotate_y(0.7)
+            .translate(4.0, 5.0, 6.0);
+        let (translation, rotation, scale) = decompose(&m);
+        assert_eq!(translation.x, 4.0);
+        assert_eq!(translation.y, 5.0);
+        assert_eq!(translation.z, 6.0);
+        assert!((scale.x - 1.0).abs() < 1e-12);
+        assert!((scale.y - 2.0).abs() < 1e-12);
+        assert!((scale.z - 3.0).abs() < 1e-12);
+        assert_close(&rotation.to_rotation(), &Matrix44::rotation_y(0.7));
+    }
+
+    #[test]
+    fn test_quaternion_motion_keeps_shape() {
+        let start = Matrix44::identity();
+        let end = Matrix44::rotation_z(std::f64::consts::PI * 0.9);
+        let motion = Motion::new(end, Interpolation::Quaternion);
+        assert_close(&motion.transform_at(&start, 0.0), &start);
+        assert_close(&motion.transform_at(&start, 1.0), &end);
+        let halfway = motion.transform_at(&start, 0.5);
+        assert_close(&halfway, &Matrix44::rotation_z(std::f64::consts::PI * 0.45));
+        // A linear blend of the same rotations shrinks the object, the slerp does not.
+        let p = halfway
+            * Point {
+                x: 1.0,
+                y: 0.0,
+                z: 0.0,
+            };
+        assert!(((p - Point::zero()).length() - 1.0).abs() < 1e-12);
+    }
+}
@@ -0,0 +1,111 @@
+use crate::camera::*;
+use crate::matrix::*;
+use crate::point::*;
+use crate::vector3::*;
+
+// Keeps orbit and pitch away from the poles, where the view direction would line up with `up`.
+const MIN_POLE_ANGLE: f64 = 0.01;
+
+// Interactive camera, an eye looking at a target. Every move keeps the camera level with `up`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Navigation {
+    pub from: Point,
+    pub to: Point,
+    pub up: Vector3,
+}
+
+impl Navigation {
+    // Picks up the placement of a camera, the target is put `distance` in front of the eye.
+    pub fn from_camera(cam: &Camera, distance: f64) -> Self {
+        let from = cam.position();
+        Navigation {
+            from,
+            to: from + cam.forward() * distance,
+            up: cam.up(),
+        }
+    }
+
+    pub fn transform(&self) -> Matrix44 {
+        view_transform(self.from, self.to, self.up)
+    }
+
+    pub fn distance(&self) -> f64 {
+        (self.to - self.from).length()
+    }
+
+    pub fn forward(&self) -> Vector3 {
+        (self.to - self.from).normalize()
+    }
+
+    // Direction pointing to the right of the image.
+    pub fn right(&self) -> Vector3 {
+        self.up.cross(&self.forward()).normalize()
+    }
+
+    // Turns the eye around the target, `yaw` around the up axis and `pitch` towards it, in radians.
+    pub fn orbit(&mut self, yaw: f64, pitch: f64) {
+        let offset = rotate_around(&(self.from - self.to), &self.up, yaw);
+        let up = self.up.normalize();
+        let angle = offset.normalize().dot(&up).clamp(-1.0, 1.0).acos();
+        let pitch = pitch.clamp(
+            angle - std::f64::consts::PI + MIN_POLE_ANGLE,
+            angle - MIN_POLE_ANGLE,
+        );
+        let axis = offset.cross(&up);
+        if axis.length() < 1e-12 {
+            // Looking straight along up, there is no way to tell which way to pitch.
+            self.from = self.to + offset;
+            return;
+        }
+        self.from = self.to + rotate_around(&offset, &axis, pitch);
+    }
+
+    // Slides eye and target together across the view, by fractions of the distance between them.
+    pub fn pan(&mut self, right: f64, up: f64) {
+        let true_up = self.forward().cross(&self.right());
+        let shift = (self.right() * right + true_up * up) * self.distance();
+        self.from = self.from + shift;
+        self.to = self.to + shift;
+    }
+
+    // Moves the eye towards the target, halving the distance for every unit of `amount`.
+    pub fn dolly(&mut self, amount: f64) {
+        let distance = self.distance() * 0.5_f64.powf(amount);
+        self.from = self.to - self.forward() * distance;
+    }
+
+    // Moves eye and target together, in world units along the view, the right and the up directions.
+    pub fn fly(&mut self, forward: f64, right: f64, up: f64) {
+        let shift = self.forward() * forward + self.right() * right + self.up.normalize() * up;
+        self.from = self.from + shift;
+        self.to = self.to + shift;
+    }
+
+    // Camera line for scene files.
+    pub fn scene_line(&self, field_of_view: f64) -> String {
+        format!(
+            "camera fov {} from {} {} {} to {} {} {} up {} {} {}",
+            field_of_view,
+            self.from.x,
+            self.from.y,
+            self.from.z,
+            self.to.x,
+            self.to.y,
+            self.to.z,
+            self.up.x,
+            self.up.y,
+            self.up.z
+        )
+    }
+}
+
+// Rodrigues rotation of a vector around a unit axis.
+fn rotate_around(v: &Vector3, axis: &Vector3, angle: f64) -> Vector3 {
+    let axis = axis.normalize();
+    let (sin, cos) = angle.sin_cos();
+    *v * cos + axis.cross(v) * sin + axis * (axis.dot(v) * (1.0 - cos))
+}
+
+#[cfg(test)]
+#[path = "./navigation_tests.rs"]
+mod navigation_tests;
@@ -0,0 +1,135 @@
+#[cfg(test)]
+mod tests {
+    use crate::navigation::*;
+
+    fn navigation() -> Navigation {
+        Navigation {
+            from: Point {
+                x: 0.0,
+                y: 0.0,
+                z: -5.0,
+            },
+            to: Point::zero(),
+            up: Vector3 {
+                x: 0.0,
+                y: 1.0,
+                z: 0.0,
+            },
+        }
+    }
+
+    fn assert_close(a: Point, b: Point) {
+        assert!((a - b).length() < 1e-9, "{} != {}", a, b);
+    }
+
+    #[test]
+    fn test_from_camera() {
+        let mut cam = Camera::new(10, 10, 1.0);
+        cam.set_transform(navigation().transform());
+        let nav = Navigation::from_camera(&cam, 5.0);
+        assert_close(nav.from, navigation().from);
+        assert_close(nav.to, navigation().to);
+        assert_eq!(nav.transform(), *cam.transform());
+    }
+
+    #[test]
+    fn test_right_is_image_right() {
+        let mut cam = Camera::new(11, 11, 1.0);
+        cam.set_transform(navigation().transform());
+        let right = navigation().right();
+        // The ray through the right edge of the image leans towards `right`.
+        assert!(cam.ray_for_pixel(10, 5).direction.dot(&right) > 0.0);
+        assert_eq!(
+            right,
+            Vector3 {
+                x: 1.0,
+                y: 0.0,
+                z: 0.0,
+            }
+        );
+    }
+
+    #[test]
+    fn test_orbit_keeps_distance() {
+        let mut nav = navigation();
+        nav.orbit(std::f64::consts::FRAC_PI_2, 0.0);
+        assert_close(nav.to, Point::zero());
+        assert!((nav.distance() - 5.0).abs() < 1e-9);
+        assert!(nav.from.z.abs() < 1e-9);
+        nav.orbit(0.0, 0.3);
+        assert!(nav.from.y > 0.0);
+        assert!((nav.distance() - 5.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_orbit_stops_before_the_pole() {
+        let mut nav = navigation();
+        nav.orbit(0.0, 10.0);
+        let angle = nav.forward().dot(&nav.up).acos();
+        assert!(angle > std::f64::consts::PI - 0.02 && angle < std::f64::consts::PI);
+        nav.orbit(0.0, -10.0);
+        let angle = nav.forward().dot(&nav.up).acos();
+        assert!(angle > 0.0 && angle < 0.02);
+    }
+
+    #[test]
+    fn test_pan() {
+        let mut nav = navigation();
+        nav.pan(0.2, -0.1);
+        assert_close(
+            nav.from,
+            Point {
+                x: 1.0,
+                y: -0.5,
+                z: -5.0,
+            },
+        );
+        assert_close(
+            nav.to,
+            Point {
+                x: 1.0,
+                y: -0.5,
+                z: 0.0,
+            },
+        );
+    }
+
+    #[test]
+    fn test_dolly() {
+        let mut nav = navigation();
+        nav.dolly(1.0);
+        assert_close(
+            nav.from,
+            Point {
+                x: 0.0,
+                y: 0.0,
+                z: -2.5,
+            },
+        );
+        nav.dolly(-2.0);
+        assert!((nav.distance() - 10.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_fly() {
+        let mut nav = navigation();
+        nav.fly(1.0, -2.0, 0.5);
+        assert_close(
+            nav.from,
+            Point {
+                x: -2.0,
+                y: 0.5,
+                z: -4.0,
+            },
+        );
+        assert!((nav.distance() - 5.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_scene_line() {
+        assert_eq!(
+            navigation().scene_line(1.5),
+            "camera fov 1.5 from 0 0 -5 to 0 0 0 up 0 1 0"
+        );
+    }
+}
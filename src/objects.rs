@@ -2,74 +2,222 @@ pub mod cube;
 pub mod plane;
 pub mod sphere;
 
+use crate::bounds::*;
 use crate::intersection::*;
 use crate::material::*;
 use crate::matrix::*;
-use crate::objects::cube::*;
-use crate::objects::plane::*;
+use crate::motion::*;
 use crate::objects::sphere::*;
 use crate::point::*;
 use crate::ray::*;
+use crate::stats;
 use crate::vector3::*;
+use std::fmt::Debug;
+use std::sync::Arc;
 
-#[derive(Clone, Debug, PartialEq)]
-pub enum Object {
-    Sphere(Sphere),
-    Plane(Plane),
-    Cube(Cube),
+// Geometry of a primitive in its own space, centered on the origin. Objects place shapes in the
+// world and take care of the transform, the material and the motion, so a new primitive only
+// needs to know about its own surface.
+pub trait Shape: Debug + Send + Sync {
+    // Distances along an object space ray to every crossing with the surface, in any order.
+    fn local_intersect(&self, ray: &Ray) -> Vec<f64>;
+
+    // Outward normal at an object space point on the surface, it does not need to be normalized.
+    fn local_normal_at(&self, point: &Point) -> Vector3;
+
+    // Object space box containing the whole surface.
+    fn bounds(&self) -> Bounds;
+
+    // Texture coordinates of an object space point on the surface, in [0.0, 1.0].
+    fn uv_at(&self, point: &Point) -> (f64, f64);
+
+    // Kind of shape, for messages. Intersection tests are counted per name, give every kind of
+    // shape its own.
+    fn name(&self) -> &'static str;
+}
+
+#[derive(Clone, Debug)]
+pub struct Object {
+    pub shape: Arc<dyn Shape>,
+    pub material: Material,
+    transform: Matrix44,
+    pub motion: Option<Motion>,
+    // Kept up to date by `set_transform`, rays and normals need them for every hit.
+    inverse: Matrix44,
+    inverse_transpose: Matrix44,
 }
 
 impl Object {
-    pub fn intersect(&self, ray: &Ray) -> Option<Vec<Intersection>> {
-        let ray2 = ray.transform(self.transform().invert());
-        match *self {
-            Object::Sphere(ref s) => s.intersect(&ray2),
-            Object::Plane(ref s) => s.intersect(&ray2),
-            Object::Cube(ref s) => s.intersect(&ray2),
+    pub fn new<S: Shape + 'static>(shape: S, material: Material) -> Self {
+        Object {
+            shape: Arc::new(shape),
+            material,
+            transform: Matrix44::identity(),
+            motion: None,
+            inverse: Matrix44::identity(),
+            inverse_transpose: Matrix44::identity(),
+        }
+    }
+
+    // Fully transparent sphere with the given refractive index.
+    pub fn glass_sphere(refractive_index: f32) -> Self {
+        Object::new(
+            Sphere,
+            Material {
+                transparency: 1.0,
+                refractive_index,
+                ..Material::default()
+            },
+        )
+    }
+
+    // Intersections share the object through its handle instead of copying it, a moving object
+    // hands out a single snapshot for all the crossings of the ray.
+    pub fn intersect(self: &Arc<Self>, ray: &Ray) -> Option<Vec<Intersection>> {
+        if self.motion.is_some() {
+            return Arc::new(self.at_time(ray.time)).intersect(ray);
+        }
+        stats::intersection_test(self.name());
+        let distances = self.shape.local_intersect(&ray.transform(self.inverse));
+        if distances.is_empty() {
+            return None;
         }
+        Some(
+            distances
+                .into_iter()
+                .map(|distance| Intersection {
+                    distance,
+                    object: Arc::clone(self),
+                })
+                .collect(),
+        )
     }
 
     pub fn normal_at(&self, hit_point: &Point) -> Vector3 {
-        let local_point = self.transform().invert() * *hit_point;
-        let local_normal = match *self {
-            Object::Sphere(ref s) => s.normal_at(&local_point),
-            Object::Plane(ref s) => s.normal_at(&local_point),
-            Object::Cube(ref s) => s.normal_at(&local_point),
-        };
-        let world_normal = self.transform().invert().transpose() * local_normal;
+        let local_point = self.inverse * *hit_point;
+        let local_normal = self.shape.local_normal_at(&local_point);
+        let world_normal = self.inverse_transpose * local_normal;
         world_normal.normalize()
     }
 
-    pub fn material(&self) -> &Material {
-        match *self {
-            Object::Sphere(ref s) => &s.material,
-            Object::Plane(ref s) => &s.material,
-            Object::Cube(ref s) => &s.material,
+    // Texture coordinates of a point on the surface, in [0.0, 1.0].
+    pub fn uv_at(&self, hit_point: &Point) -> (f64, f64) {
+        self.shape.uv_at(&(self.inverse * *hit_point))
+    }
+
+    // World space box around the object, over the whole shutter interval when it moves. Linear
+    // motion takes every point along a straight line, the boxes at both ends hold the whole path.
+    // Spinning objects sweep arcs that can leave those boxes, they are sampled along the way.
+    pub fn bounds(&self) -> Bounds {
+        let bounds = self.shape.bounds();
+        match self.motion.map(|m| m.interpolation) {
+            Some(Interpolation::Linear) => bounds
+                .transform(&self.transform_at(0.0))
+                .union(&bounds.transform(&self.transform_at(1.0))),
+            Some(Interpolation::Quaternion) => self.swept_bounds(&bounds),
+            None => bounds.transform(&self.transform),
         }
     }
 
-    pub fn set_material(&mut self, material: Material) {
-        match *self {
-            Object::Sphere(ref mut s) => s.material = material,
-            Object::Plane(ref mut s) => s.material = material,
-            Object::Cube(ref mut s) => s.material = material,
+    // Union of the boxes at evenly spaced times, padded by the farthest a corner strays from a
+    // straight line between two of them, measured halfway. That covers the circular arcs of a
+    // rotation.
+    fn swept_bounds(&self, bounds: &Bounds) -> Bounds {
+        const STEPS: u32 = 16;
+        if !bounds.is_finite() {
+            return Bounds::infinite();
         }
+        let corners_at = |time: f64| {
+            let transform = self.transform_at(time);
+            bounds.corners().map(|corner| transform * corner)
+        };
+        let mut swept = bounds.transform(&self.transform_at(0.0));
+        let mut stray: f64 = 0.0;
+        for step in 0..STEPS {
+            let start = step as f64 / STEPS as f64;
+            let end = (step + 1) as f64 / STEPS as f64;
+            let (a, middle, b) = (
+                corners_at(start),
+                corners_at((start + end) / 2.0),
+                corners_at(end),
+            );
+            for i in 0..8 {
+                let chord_middle = a[i] + (b[i] - a[i]) * 0.5;
+                stray = stray.max((middle[i] - chord_middle).length());
+            }
+            swept = swept.union(&bounds.transform(&self.transform_at(end)));
+        }
+        swept.padded(stray)
+    }
+
+    pub fn name(&self) -> &'static str {
+        self.shape.name()
+    }
+
+    pub fn material(&self) -> &Material {
+        &self.material
+    }
+
+    pub fn set_material(&mut self, material: Material) {
+        self.material = material;
     }
 
     pub fn transform(&self) -> &Matrix44 {
-        match *self {
-            Object::Sphere(ref s) => &s.transform,
-            Object::Plane(ref s) => &s.transform,
-            Object::Cube(ref s) => &s.transform,
-        }
+        &self.transform
+    }
+
+    // Inverse of the transform, takes world space points to object space.
+    pub fn inverse(&self) -> &Matrix44 {
+        &self.inverse
     }
 
     pub fn set_transform(mut self, transform: Matrix44) -> Self {
-        match self {
-            Object::Sphere(ref mut s) => s.set_transform(transform),
-            Object::Plane(ref mut s) => s.set_transform(transform),
-            Object::Cube(ref mut s) => s.set_transform(transform),
-        }
+        self.transform = transform;
+        self.inverse = transform.invert();
+        self.inverse_transpose = self.inverse.transpose();
         self
     }
+
+    pub fn motion(&self) -> Option<&Motion> {
+        self.motion.as_ref()
+    }
+
+    // Moves the object from its current transform to `end` over the shutter interval.
+    pub fn set_motion(mut self, end: Matrix44, interpolation: Interpolation) -> Self {
+        self.motion = Some(Motion::new(end, interpolation));
+        self
+    }
+
+    pub fn transform_at(&self, time: f64) -> Matrix44 {
+        match self.motion() {
+            Some(motion) => motion.transform_at(self.transform(), time),
+            None => *self.transform(),
+        }
+    }
+
+    // Static copy of the object frozen at the given time, intersections of a moving object hold
+    // such a copy so normals and patterns are evaluated where the object was when the ray hit it.
+    pub fn at_time(&self, time: f64) -> Object {
+        Object {
+            motion: None,
+            ..self.clone()
+        }
+        .set_transform(self.transform_at(time))
+    }
 }
+
+// Objects are equal when they share the same shape, placement, material and motion, as clones
+// do. Two equal objects can still be different objects of a world, compare handles with
+// `Arc::ptr_eq` for that.
+impl PartialEq for Object {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.shape, &other.shape)
+            && self.transform == other.transform
+            && self.material == other.material
+            && self.motion == other.motion
+    }
+}
+
+#[cfg(test)]
+#[path = "./objects_tests.rs"]
+mod objects_tests;
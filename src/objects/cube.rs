@@ -1,32 +1,49 @@
-use std::cmp::max;
-
-use crate::intersection::*;
-use crate::material::*;
-use crate::matrix::*;
+use crate::bounds::*;
 use crate::objects::*;
 use crate::point::Point;
 use crate::ray::*;
 use crate::vector3::Vector3;
 
-#[derive(Clone, Debug)]
-pub struct Cube {
-    pub material: Material,
-    pub transform: Matrix44,
-}
+// Axis aligned cube from -1 to 1 on every axis.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Cube;
 
 impl Cube {
-    pub fn new(material: &Material) -> Self {
-        Cube {
-            material: material.clone(),
-            transform: Matrix44::identity(),
+    fn check_axis(&self, origin: f64, direction: f64) -> (f64, f64) {
+        let tmin_numerator = -1.0 - origin;
+        let tmax_numerator = 1.0 - origin;
+        let tmin;
+        let tmax;
+        if direction.abs() >= std::f64::EPSILON {
+            tmin = tmin_numerator / direction;
+            tmax = tmax_numerator / direction;
+        } else {
+            tmin = tmin_numerator * std::f64::INFINITY;
+            tmax = tmax_numerator * std::f64::INFINITY;
         }
+        if tmin > tmax {
+            return (tmax, tmin);
+        }
+        (tmin, tmax)
     }
+}
 
-    pub fn set_transform(&mut self, transform: Matrix44) {
-        self.transform = transform;
+impl Shape for Cube {
+    fn local_intersect(&self, ray: &Ray) -> Vec<f64> {
+        let xminmax = self.check_axis(ray.origin.x, ray.direction.x);
+        let yminmax = self.check_axis(ray.origin.y, ray.direction.y);
+        let zminmax = self.check_axis(ray.origin.z, ray.direction.z);
+        let tmin = vec![xminmax.0, yminmax.0, zminmax.0];
+        let tmin = tmin.iter().copied().fold(f64::NAN, f64::max);
+        let tmax = vec![xminmax.1, yminmax.1, zminmax.1];
+        let tmax = tmax.iter().copied().fold(f64::NAN, f64::min);
+        if tmin > tmax {
+            return vec![];
+        }
+        vec![tmin, tmax]
     }
 
-    pub fn normal_at(&self, point: &Point) -> Vector3 {
+    fn local_normal_at(&self, point: &Point) -> Vector3 {
         let maxc = vec![point.x.abs(), point.y.abs(), point.z.abs()];
         let maxc = maxc.iter().copied().fold(f64::NAN, f64::max);
 
@@ -50,51 +67,36 @@ impl Cube {
         }
     }
 
-    pub fn intersect(&self, ray: &Ray) -> Option<Vec<Intersection>> {
-        let xminmax = self.check_axis(ray.origin.x, ray.direction.x);
-        let yminmax = self.check_axis(ray.origin.y, ray.direction.y);
-        let zminmax = self.check_axis(ray.origin.z, ray.direction.z);
-        let tmin = vec![xminmax.0, yminmax.0, zminmax.0];
-        let tmin = tmin.iter().copied().fold(f64::NAN, f64::max);
-        let tmax = vec![xminmax.1, yminmax.1, zminmax.1];
-        let tmax = tmax.iter().copied().fold(f64::NAN, f64::min);
-        if tmin > tmax {
-            return None;
-        }
-        Some(vec![
-            Intersection {
-                distance: tmin,
-                object: Object::Cube(self.clone()),
+    fn bounds(&self) -> Bounds {
+        Bounds::new(
+            Point {
+                x: -1.0,
+                y: -1.0,
+                z: -1.0,
             },
-            Intersection {
-                distance: tmax,
-                object: Object::Cube(self.clone()),
+            Point {
+                x: 1.0,
+                y: 1.0,
+                z: 1.0,
             },
-        ])
+        )
     }
 
-    fn check_axis(&self, origin: f64, direction: f64) -> (f64, f64) {
-        let tmin_numerator = -1.0 - origin;
-        let tmax_numerator = 1.0 - origin;
-        let tmin;
-        let tmax;
-        if direction.abs() >= std::f64::EPSILON {
-            tmin = tmin_numerator / direction;
-            tmax = tmax_numerator / direction;
+    // Each face is mapped on its own, from [-1, 1] to [0, 1] along the two other axes.
+    fn uv_at(&self, point: &Point) -> (f64, f64) {
+        let unit = |c: f64| (c + 1.0) / 2.0;
+        let (x, y, z) = (point.x.abs(), point.y.abs(), point.z.abs());
+        if x >= y && x >= z {
+            (unit(point.z * -point.x.signum()), unit(point.y))
+        } else if y >= z {
+            (unit(point.x), unit(point.z * -point.y.signum()))
         } else {
-            tmin = tmin_numerator * std::f64::INFINITY;
-            tmax = tmax_numerator * std::f64::INFINITY;
-        }
-        if tmin > tmax {
-            return (tmax, tmin);
+            (unit(point.x * point.z.signum()), unit(point.y))
         }
-        (tmin, tmax)
     }
-}
 
-impl PartialEq for Cube {
-    fn eq(&self, other: &Self) -> bool {
-        self.material == other.material && self.transform == other.transform
+    fn name(&self) -> &'static str {
+        "cube"
     }
 }
 
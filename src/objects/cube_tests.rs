@@ -6,6 +6,7 @@ mod tests {
     use crate::point::Point;
     use crate::ray::Ray;
     use crate::vector3::Vector3;
+    use std::sync::Arc;
     #[test]
     fn test_ray_intersect_cube() {
         pub struct Test {
@@ -13,7 +14,7 @@ mod tests {
             pub t1: f64,
             pub t2: f64,
         }
-        let c = Cube::new(&Material::default());
+        let c = Arc::new(Object::new(Cube, Material::default()));
         let tests = vec![
             Test {
                 ray: Ray {
@@ -27,6 +28,7 @@ mod tests {
                         y: 0.0,
                         z: 0.0,
                     },
+                    time: 0.0,
                 },
                 t1: 4.0,
                 t2: 6.0,
@@ -43,6 +45,7 @@ mod tests {
                         y: 0.0,
                         z: 0.0,
                     },
+                    time: 0.0,
                 },
                 t1: 4.0,
                 t2: 6.0,
@@ -59,6 +62,7 @@ mod tests {
                         y: -1.0,
                         z: 0.0,
                     },
+                    time: 0.0,
                 },
                 t1: 4.0,
                 t2: 6.0,
@@ -75,6 +79,7 @@ mod tests {
                         y: 1.0,
                         z: 0.0,
                     },
+                    time: 0.0,
                 },
                 t1: 4.0,
                 t2: 6.0,
@@ -91,6 +96,7 @@ mod tests {
                         y: 0.0,
                         z: -1.0,
                     },
+                    time: 0.0,
                 },
                 t1: 4.0,
                 t2: 6.0,
@@ -107,6 +113,7 @@ mod tests {
                         y: 0.0,
                         z: 1.0,
                     },
+                    time: 0.0,
                 },
                 t1: 4.0,
                 t2: 6.0,
@@ -123,7 +130,7 @@ mod tests {
 
     #[test]
     fn ray_miss_cube() {
-        let c = Cube::new(&Material::default());
+        let c = Arc::new(Object::new(Cube, Material::default()));
         let tests = vec![
             Ray {
                 origin: Point {
@@ -136,6 +143,7 @@ mod tests {
                     y: 0.5345,
                     z: 0.8018,
                 },
+                time: 0.0,
             },
             Ray {
                 origin: Point {
@@ -148,6 +156,7 @@ mod tests {
                     y: 0.2673,
                     z: 0.5345,
                 },
+                time: 0.0,
             },
             Ray {
                 origin: Point {
@@ -160,6 +169,7 @@ mod tests {
                     y: 0.8018,
                     z: 0.2673,
                 },
+                time: 0.0,
             },
             Ray {
                 origin: Point {
@@ -172,6 +182,7 @@ mod tests {
                     y: 0.0,
                     z: -1.0,
                 },
+                time: 0.0,
             },
             Ray {
                 origin: Point {
@@ -184,6 +195,7 @@ mod tests {
                     y: -1.0,
                     z: 0.0,
                 },
+                time: 0.0,
             },
             Ray {
                 origin: Point {
@@ -196,6 +208,7 @@ mod tests {
                     y: 0.0,
                     z: 0.0,
                 },
+                time: 0.0,
             },
         ];
         for test in tests {
@@ -210,7 +223,7 @@ mod tests {
             pub point: Point,
             pub normal: Vector3,
         }
-        let c = Object::Cube(Cube::new(&Material::default()));
+        let c = Object::new(Cube, Material::default());
         let tests = vec![
             Test {
                 point: Point {
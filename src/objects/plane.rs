@@ -1,32 +1,22 @@
-use crate::intersection::*;
-use crate::material::*;
-use crate::matrix::*;
+use crate::bounds::*;
 use crate::objects::*;
 use crate::point::Point;
 use crate::ray::*;
 use crate::vector3::Vector3;
 
-#[derive(Clone, Debug)]
-pub struct Plane {
-    pub origin: Point,
-    pub material: Material,
-    pub transform: Matrix44,
-}
+// The xz plane, facing up.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Plane;
 
-impl Plane {
-    pub fn new(material: Material) -> Self {
-        Plane {
-            origin: Point::zero(),
-            material,
-            transform: Matrix44::identity(),
+impl Shape for Plane {
+    fn local_intersect(&self, ray: &Ray) -> Vec<f64> {
+        if ray.direction.y.abs() < f64::EPSILON {
+            return vec![];
         }
+        vec![-ray.origin.y / ray.direction.y]
     }
 
-    pub fn set_transform(&mut self, transform: Matrix44) {
-        self.transform = transform;
-    }
-
-    pub fn normal_at(&self, _point: &Point) -> Vector3 {
+    fn local_normal_at(&self, _point: &Point) -> Vector3 {
         Vector3 {
             x: 0.0,
             y: 1.0,
@@ -34,21 +24,27 @@ impl Plane {
         }
     }
 
-    pub fn intersect(&self, ray: &Ray) -> Option<Vec<Intersection>> {
-        if ray.direction.y.abs() < f64::EPSILON {
-            return None;
-        }
-        let t = -ray.origin.y / ray.direction.y;
-        Some(vec![Intersection {
-            distance: t,
-            object: Object::Plane(self.clone()),
-        }])
+    fn bounds(&self) -> Bounds {
+        let infinite = Bounds::infinite();
+        Bounds::new(
+            Point {
+                y: 0.0,
+                ..infinite.min
+            },
+            Point {
+                y: 0.0,
+                ..infinite.max
+            },
+        )
+    }
+
+    // Tiles the plane with unit squares.
+    fn uv_at(&self, point: &Point) -> (f64, f64) {
+        (point.x.rem_euclid(1.0), point.z.rem_euclid(1.0))
     }
-}
 
-impl PartialEq for Plane {
-    fn eq(&self, other: &Self) -> bool {
-        self.origin == other.origin && self.transform == other.transform
+    fn name(&self) -> &'static str {
+        "plane"
     }
 }
 
@@ -6,7 +6,7 @@ mod tests {
 
     #[test]
     fn test_plane_normal() {
-        let p = Plane::new(Material::default());
+        let p = Object::new(Plane, Material::default());
         let n1 = p.normal_at(&Point::zero());
         let n2 = p.normal_at(&Point {
             x: 10.0,
@@ -46,7 +46,7 @@ mod tests {
 
     #[test]
     fn test_ray_plane_parallel() {
-        let p = Plane::new(Material::default());
+        let p = Arc::new(Object::new(Plane, Material::default()));
         let r = Ray {
             origin: Point {
                 x: 0.0,
@@ -58,13 +58,14 @@ mod tests {
                 y: 0.0,
                 z: 1.0,
             },
+            time: 0.0,
         };
         assert_eq!(p.intersect(&r), None);
     }
 
     #[test]
     fn test_coplanar_ray_intersect() {
-        let p = Plane::new(Material::default());
+        let p = Arc::new(Object::new(Plane, Material::default()));
         let r = Ray {
             origin: Point::zero(),
             direction: Vector3 {
@@ -72,13 +73,14 @@ mod tests {
                 y: 0.0,
                 z: 1.0,
             },
+            time: 0.0,
         };
         assert_eq!(p.intersect(&r), None);
     }
 
     #[test]
     fn test_ray_intersect_plane_from_above() {
-        let p = Plane::new(Material::default());
+        let p = Arc::new(Object::new(Plane, Material::default()));
         let r = Ray {
             origin: Point {
                 x: 0.0,
@@ -90,6 +92,7 @@ mod tests {
                 y: -1.0,
                 z: 0.0,
             },
+            time: 0.0,
         };
         let i = p.intersect(&r);
         assert_eq!(i.unwrap()[0].distance, 1.0);
@@ -97,7 +100,7 @@ mod tests {
 
     #[test]
     fn test_ray_intersect_plane_from_below() {
-        let p = Plane::new(Material::default());
+        let p = Arc::new(Object::new(Plane, Material::default()));
         let r = Ray {
             origin: Point {
                 x: 0.0,
@@ -109,6 +112,7 @@ mod tests {
                 y: 1.0,
                 z: 0.0,
             },
+            time: 0.0,
         };
         let i = p.intersect(&r);
         assert_eq!(i.unwrap()[0].distance, 1.0);
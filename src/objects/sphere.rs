@@ -1,47 +1,29 @@
-use crate::intersection::*;
-use crate::material::*;
-use crate::matrix::*;
+use crate::bounds::*;
 use crate::objects::*;
 use crate::point::Point;
 use crate::ray::*;
 use crate::vector3::Vector3;
 
-#[derive(Clone, Debug)]
-pub struct Sphere {
-    pub center: Point,
-    pub radius: f64,
-    pub material: Material,
-    pub transform: Matrix44,
-}
-
-impl Sphere {
-    pub fn new(material: &Material) -> Self {
-        Sphere {
-            center: Point::zero(),
-            radius: 1.0,
-            material: material.clone(),
-            transform: Matrix44::identity(),
-        }
-    }
+// Unit sphere around the origin.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Sphere;
 
-    pub fn glass(refractive_index: f32) -> Self {
-        Sphere {
-            center: Point::zero(),
-            radius: 1.0,
-            material: Material {
-                transparency: 1.0,
-                refractive_index,
-                ..Material::default()
-            },
-            transform: Matrix44::identity(),
+impl Shape for Sphere {
+    fn local_intersect(&self, ray: &Ray) -> Vec<f64> {
+        let sphere_to_ray = ray.origin - Point::zero();
+        let a = ray.direction.dot(&ray.direction);
+        let b = 2.0 * ray.direction.dot(&sphere_to_ray);
+        let c = sphere_to_ray.dot(&sphere_to_ray) - 1.0;
+        let discriminant = b * b - 4.0 * a * c;
+        if discriminant < 0.0 {
+            return vec![];
         }
+        let t1 = (-b - discriminant.sqrt()) / (2.0 * a);
+        let t2 = (-b + discriminant.sqrt()) / (2.0 * a);
+        vec![t1, t2]
     }
 
-    pub fn set_transform(&mut self, transform: Matrix44) {
-        self.transform = transform;
-    }
-
-    pub fn normal_at(&self, point: &Point) -> Vector3 {
+    fn local_normal_at(&self, point: &Point) -> Vector3 {
         Vector3 {
             x: point.x,
             y: point.y,
@@ -49,35 +31,32 @@ impl Sphere {
         }
     }
 
-    pub fn intersect(&self, ray: &Ray) -> Option<Vec<Intersection>> {
-        let sphere_to_ray = ray.origin - Point::zero();
-        let a = ray.direction.dot(&ray.direction);
-        let b = 2.0 * ray.direction.dot(&sphere_to_ray);
-        let c = sphere_to_ray.dot(&sphere_to_ray) - 1.0;
-        let discriminant = b * b - 4.0 * a * c;
-        if discriminant >= 0.0 {
-            let t1 = (-b - discriminant.sqrt()) / (2.0 * a);
-            let t2 = (-b + discriminant.sqrt()) / (2.0 * a);
-            return Some(vec![
-                Intersection {
-                    distance: t1,
-                    object: Object::Sphere(self.clone()),
-                },
-                Intersection {
-                    distance: t2,
-                    object: Object::Sphere(self.clone()),
-                },
-            ]);
-        }
-        None
+    fn bounds(&self) -> Bounds {
+        Bounds::new(
+            Point {
+                x: -1.0,
+                y: -1.0,
+                z: -1.0,
+            },
+            Point {
+                x: 1.0,
+                y: 1.0,
+                z: 1.0,
+            },
+        )
+    }
+
+    // Latitude and longitude, u goes once around the y axis and v from the bottom to the top pole.
+    fn uv_at(&self, point: &Point) -> (f64, f64) {
+        let theta = point.x.atan2(point.z);
+        let radius = (point.x * point.x + point.y * point.y + point.z * point.z).sqrt();
+        let phi = (point.y / radius).clamp(-1.0, 1.0).acos();
+        let raw_u = theta / (2.0 * std::f64::consts::PI);
+        (1.0 - (raw_u + 0.5), 1.0 - phi / std::f64::consts::PI)
     }
-}
 
-impl PartialEq for Sphere {
-    fn eq(&self, other: &Self) -> bool {
-        self.center == other.center
-            && self.radius == other.radius
-            && self.transform == other.transform
+    fn name(&self) -> &'static str {
+        "sphere"
     }
 }
 
@@ -5,10 +5,10 @@ mod tests {
     use crate::vector3::Vector3;
     #[test]
     fn test_default_transformation() {
-        let mut s = Sphere::new(&Material::default());
+        let s = Object::new(Sphere, Material::default());
         assert_eq!(s.transform, Matrix44::identity());
         let t = Matrix44::translation(2.0, 3.0, 4.0);
-        s.set_transform(t);
+        let s = s.set_transform(t);
         assert_eq!(s.transform, t);
     }
 
@@ -25,9 +25,12 @@ mod tests {
                 y: 0.0,
                 z: 1.0,
             },
+            time: 0.0,
         };
-        let s = Object::Sphere(Sphere::new(&Material::default()))
-            .set_transform(Matrix44::translation(5.0, 0.0, 0.0));
+        let s = Arc::new(
+            Object::new(Sphere, Material::default())
+                .set_transform(Matrix44::translation(5.0, 0.0, 0.0)),
+        );
         let i = s.intersect(&r);
         assert_eq!(i, None);
     }
@@ -45,9 +48,12 @@ mod tests {
                 y: 0.0,
                 z: 1.0,
             },
+            time: 0.0,
         };
-        let s = Object::Sphere(Sphere::new(&Material::default()))
-            .set_transform(Matrix44::scaling(2.0, 2.0, 2.0));
+        let s = Arc::new(
+            Object::new(Sphere, Material::default())
+                .set_transform(Matrix44::scaling(2.0, 2.0, 2.0)),
+        );
         let i = s.intersect(&r);
         assert!(Option::is_some(&i));
         let u = i.unwrap();
@@ -57,7 +63,7 @@ mod tests {
 
     #[test]
     fn test_normal_at() {
-        let s = Sphere::new(&Material::default());
+        let s = Object::new(Sphere, Material::default());
         assert_eq!(
             s.normal_at(&Point {
                 x: 1.0,
@@ -73,7 +79,7 @@ mod tests {
     }
     #[test]
     fn test_normal_at_2() {
-        let s = Sphere::new(&Material::default());
+        let s = Object::new(Sphere, Material::default());
         assert_eq!(
             s.normal_at(&Point {
                 x: 0.0,
@@ -89,7 +95,7 @@ mod tests {
     }
     #[test]
     fn test_normal_at_3() {
-        let s = Sphere::new(&Material::default());
+        let s = Object::new(Sphere, Material::default());
         assert_eq!(
             s.normal_at(&Point {
                 x: 0.0,
@@ -105,7 +111,7 @@ mod tests {
     }
     #[test]
     fn test_normal_at_4() {
-        let s = Sphere::new(&Material::default());
+        let s = Object::new(Sphere, Material::default());
         assert_eq!(
             s.normal_at(&Point {
                 x: 0.0,
@@ -123,7 +129,7 @@ mod tests {
 
     #[test]
     fn test_normal_is_normalized_vec() {
-        let s = Sphere::new(&Material::default());
+        let s = Object::new(Sphere, Material::default());
         let n = s.normal_at(&Point {
             x: 3.0_f64.sqrt() / 3.0,
             y: 3.0_f64.sqrt() / 3.0,
@@ -134,7 +140,7 @@ mod tests {
 
     #[test]
     fn test_normal_at_nonaxial_point() {
-        let s = Sphere::new(&Material::default());
+        let s = Object::new(Sphere, Material::default());
         assert_eq!(
             s.normal_at(&Point {
                 x: 3.0_f64.sqrt() / 3.0,
@@ -151,7 +157,7 @@ mod tests {
 
     #[test]
     fn test_normal_at_translated() {
-        let s = Object::Sphere(Sphere::new(&Material::default()))
+        let s = Object::new(Sphere, Material::default())
             .set_transform(Matrix44::translation(0.0, 1.0, 0.0));
         assert_eq!(
             s.normal_at(&Point {
@@ -169,7 +175,7 @@ mod tests {
 
     #[test]
     fn test_normal_on_transformed() {
-        let s = Object::Sphere(Sphere::new(&Material::default()))
+        let s = Object::new(Sphere, Material::default())
             .set_transform(Matrix44::rotation_z(std::f64::consts::PI / 5.0).scale(1.0, 0.5, 1.0));
         assert_eq!(
             s.normal_at(&Point {
@@ -184,4 +190,34 @@ mod tests {
             }
         );
     }
+
+    #[test]
+    fn test_moving_sphere_intersect() {
+        let s = Arc::new(
+            Object::new(Sphere, Material::default())
+                .set_motion(Matrix44::translation(5.0, 0.0, 0.0), Interpolation::Linear),
+        );
+        let ray_at = |time: f64| Ray {
+            origin: Point {
+                x: 5.0,
+                y: 0.0,
+                z: -5.0,
+            },
+            direction: Vector3 {
+                x: 0.0,
+                y: 0.0,
+                z: 1.0,
+            },
+            time,
+        };
+        assert_eq!(s.intersect(&ray_at(0.0)), None);
+        let i = s.intersect(&ray_at(1.0)).unwrap();
+        assert_eq!(i[0].distance, 4.0);
+        assert_eq!(i[1].distance, 6.0);
+        assert_eq!(
+            i[0].object.transform(),
+            &Matrix44::translation(5.0, 0.0, 0.0)
+        );
+        assert_eq!(i[0].object.motion(), None);
+    }
 }
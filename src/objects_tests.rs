@@ -0,0 +1,158 @@
+#[cfg(test)]
+mod tests {
+    use crate::color::*;
+    use crate::intersection::*;
+    use crate::objects::cube::*;
+    use crate::objects::plane::*;
+    use crate::objects::*;
+    use crate::world::*;
+
+    // Flat disc of radius 1 in the xz plane, the kind of primitive a user crate would add.
+    #[derive(Debug)]
+    struct Disc;
+
+    impl Shape for Disc {
+        fn local_intersect(&self, ray: &Ray) -> Vec<f64> {
+            if ray.direction.y.abs() < f64::EPSILON {
+                return vec![];
+            }
+            let t = -ray.origin.y / ray.direction.y;
+            let p = ray.position(t);
+            if p.x * p.x + p.z * p.z > 1.0 {
+                return vec![];
+            }
+            vec![t]
+        }
+
+        fn local_normal_at(&self, _point: &Point) -> Vector3 {
+            Vector3 {
+                x: 0.0,
+                y: 1.0,
+                z: 0.0,
+            }
+        }
+
+        fn bounds(&self) -> Bounds {
+            Bounds::new(
+                Point {
+                    x: -1.0,
+                    y: 0.0,
+                    z: -1.0,
+                },
+                Point {
+                    x: 1.0,
+                    y: 0.0,
+                    z: 1.0,
+                },
+            )
+        }
+
+        fn uv_at(&self, point: &Point) -> (f64, f64) {
+            ((point.x + 1.0) / 2.0, (point.z + 1.0) / 2.0)
+        }
+
+        fn name(&self) -> &'static str {
+            "disc"
+        }
+    }
+
+    fn down_ray(x: f64) -> Ray {
+        Ray {
+            origin: Point { x, y: 5.0, z: 0.0 },
+            direction: Vector3 {
+                x: 0.0,
+                y: -1.0,
+                z: 0.0,
+            },
+            time: 0.0,
+        }
+    }
+
+    #[test]
+    fn test_custom_shape_in_world() {
+        let disc = Object::new(
+            Disc,
+            Material {
+                color: Color::red(),
+                ..Material::default()
+            },
+        )
+        .set_transform(Matrix44::scaling(2.0, 2.0, 2.0).translate(0.0, 1.0, 0.0));
+        let world = World::new(vec![disc], World::default().lights);
+        let xs = intersect_world(&down_ray(1.5), &world);
+        assert_eq!(xs.len(), 1);
+        assert_eq!(xs[0].distance, 4.0);
+        assert_eq!(xs[0].object.name(), "disc");
+        assert!(intersect_world(&down_ray(2.5), &world).is_empty());
+        assert_ne!(color_at(&world, &down_ray(0.0), 5), Color::black());
+        assert_eq!(
+            world.objects[0].uv_at(&Point {
+                x: 2.0,
+                y: 1.0,
+                z: 0.0,
+            }),
+            (1.0, 0.5)
+        );
+    }
+
+    #[test]
+    fn test_world_bounds() {
+        let cube = Object::new(Cube, Material::default())
+            .set_transform(Matrix44::translation(1.0, 2.0, 3.0));
+        assert_eq!(
+            cube.bounds(),
+            Bounds::new(
+                Point {
+                    x: 0.0,
+                    y: 1.0,
+                    z: 2.0,
+                },
+                Point {
+                    x: 2.0,
+                    y: 3.0,
+                    z: 4.0,
+                },
+            )
+        );
+        let moving = cube.set_motion(Matrix44::translation(5.0, 2.0, 3.0), Interpolation::Linear);
+        assert_eq!(moving.bounds().max.x, 6.0);
+        assert!(!Object::new(Plane, Material::default()).bounds().is_finite());
+    }
+
+    #[test]
+    fn test_spinning_bounds() {
+        // A quarter turn ends on the same box it started from, the corners swing out on the way.
+        let cube = Object::new(Cube, Material::default()).set_motion(
+            Matrix44::rotation_y(std::f64::consts::FRAC_PI_2),
+            Interpolation::Quaternion,
+        );
+        let bounds = cube.bounds();
+        let halfway = cube.at_time(0.5).bounds();
+        assert!(halfway.max.x > 1.4);
+        assert!(bounds.max.x >= halfway.max.x && bounds.min.z <= halfway.min.z);
+        assert!(bounds.max.x < 1.5);
+        assert!(bounds.max.y < 1.01);
+    }
+
+    #[test]
+    fn test_equality() {
+        let sphere = Object::new(Sphere, Material::default());
+        assert_eq!(sphere, sphere.clone());
+        // Separate shapes are different, whatever their names.
+        assert_ne!(sphere, Object::new(Sphere, Material::default()));
+        let moving = sphere
+            .clone()
+            .set_motion(Matrix44::translation(1.0, 0.0, 0.0), Interpolation::Linear);
+        assert_ne!(sphere, moving);
+    }
+
+    #[test]
+    fn test_cached_inverse_follows_transform() {
+        let transform = Matrix44::scaling(2.0, 1.0, 1.0).rotate_y(0.5);
+        let cube = Object::new(Cube, Material::default()).set_transform(transform);
+        assert_eq!(*cube.inverse(), transform.invert());
+        let moved = cube.set_motion(Matrix44::translation(0.0, 4.0, 0.0), Interpolation::Linear);
+        let frozen = moved.at_time(0.5);
+        assert_eq!(*frozen.inverse(), moved.transform_at(0.5).invert());
+    }
+}
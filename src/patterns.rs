@@ -1,88 +1,81 @@
 pub mod blend;
 pub mod checker;
+pub mod color_ramp;
+pub mod fractal;
 pub mod gradient;
 pub mod perturbed;
 pub mod radial_gradient;
 pub mod ring;
 pub mod solid_color;
 pub mod stripe;
+pub mod worley;
 
 use crate::color::*;
 use crate::matrix::*;
 use crate::objects::*;
-use crate::patterns::blend::*;
-use crate::patterns::checker::*;
-use crate::patterns::gradient::*;
-use crate::patterns::perturbed::*;
-use crate::patterns::radial_gradient::*;
-use crate::patterns::ring::*;
-use crate::patterns::solid_color::*;
-use crate::patterns::stripe::*;
 use crate::point::*;
+use std::fmt::Debug;
+use std::sync::Arc;
+
+// Procedural texture in its own space. Patterns place them on objects and take care of the
+// transform, nested patterns go through `Pattern` so each level keeps its own transform.
+pub trait PatternFn: Debug + Send + Sync {
+    // Color at a point in the pattern's own space.
+    fn pattern_at(&self, point: &Point) -> Color;
+}
 
 #[derive(Clone, Debug)]
-pub enum Pattern {
-    Stripe(Stripe),
-    Gradient(Gradient),
-    RadialGradient(RadialGradient),
-    Ring(Ring),
-    Checker(Checker),
-    SolidColor(SolidColor),
-    Perturbed(Perturbed),
-    Blend(Blend),
-    TestPattern,
+pub struct Pattern {
+    pub function: Arc<dyn PatternFn>,
+    transform: Matrix44,
+    // Kept up to date by `set_transform`.
+    inverse: Matrix44,
 }
 
 impl Pattern {
-    pub fn pattern_at_object(&self, point: &Point, object: &Object) -> Color {
-        let object_point = object.transform().invert() * *point;
-        match *self {
-            Pattern::Stripe(ref s) => s.pattern_at(&object_point),
-            Pattern::Gradient(ref s) => s.pattern_at(&object_point),
-            Pattern::Ring(ref s) => s.pattern_at(&object_point),
-            Pattern::Checker(ref s) => s.pattern_at(&object_point),
-            Pattern::RadialGradient(ref s) => s.pattern_at(&object_point),
-            Pattern::SolidColor(ref s) => s.pattern_at(&object_point),
-            Pattern::Blend(ref s) => s.pattern_at(&object_point),
-            Pattern::Perturbed(ref s) => s.pattern_at(&object_point),
-            Pattern::TestPattern => Color {
-                red: point.x as f32,
-                green: point.y as f32,
-                blue: point.z as f32,
-            },
+    pub fn new<P: PatternFn + 'static>(function: P) -> Self {
+        Pattern {
+            function: Arc::new(function),
+            transform: Matrix44::identity(),
+            inverse: Matrix44::identity(),
         }
     }
 
+    pub fn pattern_at_object(&self, point: &Point, object: &Object) -> Color {
+        let object_point = *object.inverse() * *point;
+        self.pattern_at(&object_point)
+    }
+
+    // Color at a point given in the space of whatever holds the pattern, an object or a parent pattern.
     pub fn pattern_at(&self, point: &Point) -> Color {
-        match *self {
-            Pattern::Stripe(ref s) => s.pattern_at(&point),
-            Pattern::Gradient(ref s) => s.pattern_at(&point),
-            Pattern::Ring(ref s) => s.pattern_at(&point),
-            Pattern::Checker(ref s) => s.pattern_at(&point),
-            Pattern::RadialGradient(ref s) => s.pattern_at(&point),
-            Pattern::SolidColor(ref s) => s.pattern_at(&point),
-            Pattern::Blend(ref s) => s.pattern_at(&point),
-            Pattern::Perturbed(ref s) => s.pattern_at(&point),
-            Pattern::TestPattern => Color {
-                red: point.x as f32,
-                green: point.y as f32,
-                blue: point.z as f32,
-            },
-        }
+        self.function.pattern_at(&(self.inverse * *point))
+    }
+
+    pub fn transform(&self) -> &Matrix44 {
+        &self.transform
     }
 
     pub fn set_transform(mut self, transform: Matrix44) -> Self {
-        match self {
-            Pattern::Stripe(ref mut s) => s.transform = transform,
-            Pattern::Gradient(ref mut s) => s.transform = transform,
-            Pattern::Ring(ref mut s) => s.transform = transform,
-            Pattern::Checker(ref mut s) => s.transform = transform,
-            Pattern::RadialGradient(ref mut s) => s.transform = transform,
-            Pattern::Blend(ref mut s) => s.transform = transform,
-            Pattern::Perturbed(ref mut s) => s.transform = transform,
-            Pattern::SolidColor(ref mut _s) => {}
-            Pattern::TestPattern => {}
-        }
+        self.transform = transform;
+        self.inverse = transform.invert();
         self
     }
 }
+
+// Shows the coordinates it is evaluated at, for tests.
+#[derive(Clone, Copy, Debug)]
+pub struct TestPattern;
+
+impl PatternFn for TestPattern {
+    fn pattern_at(&self, point: &Point) -> Color {
+        Color {
+            red: point.x as f32,
+            green: point.y as f32,
+            blue: point.z as f32,
+        }
+    }
+}
+
+#[cfg(test)]
+#[path = "./patterns_tests.rs"]
+mod patterns_tests;
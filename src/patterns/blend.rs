@@ -1,5 +1,4 @@
 use crate::color::*;
-use crate::matrix::*;
 use crate::patterns::*;
 use crate::point::*;
 
@@ -7,7 +6,6 @@ use crate::point::*;
 pub struct Blend {
     pub pattern_a: Box<Pattern>,
     pub pattern_b: Box<Pattern>,
-    pub transform: Matrix44,
     pub blend_ratio: f32,
 }
 
@@ -17,13 +15,13 @@ impl Blend {
             pattern_a: Box::new(pattern_a),
             pattern_b: Box::new(pattern_b),
             blend_ratio,
-            transform: Matrix44::identity(),
         }
     }
+}
 
-    pub fn pattern_at(&self, point: &Point) -> Color {
-        let pt = self.transform.invert() * *point;
-        self.pattern_a.pattern_at(&pt) * (1.0 - self.blend_ratio)
-            + self.pattern_b.pattern_at(&pt) * self.blend_ratio
+impl PatternFn for Blend {
+    fn pattern_at(&self, point: &Point) -> Color {
+        self.pattern_a.pattern_at(point) * (1.0 - self.blend_ratio)
+            + self.pattern_b.pattern_at(point) * self.blend_ratio
     }
 }
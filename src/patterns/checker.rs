@@ -1,5 +1,4 @@
 use crate::color::*;
-use crate::matrix::*;
 use crate::patterns::*;
 use crate::point::*;
 
@@ -7,7 +6,6 @@ use crate::point::*;
 pub struct Checker {
     pub pattern_a: Box<Pattern>,
     pub pattern_b: Box<Pattern>,
-    pub transform: Matrix44,
 }
 
 impl Checker {
@@ -15,21 +13,21 @@ impl Checker {
         Checker {
             pattern_a: Box::new(pattern_a),
             pattern_b: Box::new(pattern_b),
-            transform: Matrix44::identity(),
         }
     }
+}
 
-    pub fn pattern_at(&self, point: &Point) -> Color {
-        let pt = self.transform.invert() * *point;
-        if ((pt.x + std::f64::EPSILON).floor()
-            + (pt.y + std::f64::EPSILON).floor()
-            + (pt.z + std::f64::EPSILON).floor())
+impl PatternFn for Checker {
+    fn pattern_at(&self, point: &Point) -> Color {
+        if ((point.x + std::f64::EPSILON).floor()
+            + (point.y + std::f64::EPSILON).floor()
+            + (point.z + std::f64::EPSILON).floor())
             % 2.0
             == 0.0
         {
-            return self.pattern_a.pattern_at(&pt);
+            return self.pattern_a.pattern_at(point);
         }
-        self.pattern_b.pattern_at(&pt)
+        self.pattern_b.pattern_at(point)
     }
 }
 
@@ -1,12 +1,13 @@
 #[cfg(test)]
 mod tests {
     use crate::patterns::checker::*;
+    use crate::patterns::solid_color::*;
 
     #[test]
     fn checker_repeat_in_x() {
         let checker = Checker::new(
-            Pattern::SolidColor(SolidColor::new(Color::white())),
-            Pattern::SolidColor(SolidColor::new(Color::black())),
+            Pattern::new(SolidColor::new(Color::white())),
+            Pattern::new(SolidColor::new(Color::black())),
         );
         assert_eq!(checker.pattern_at(&Point::zero()), Color::white());
         assert_eq!(
@@ -29,8 +30,8 @@ mod tests {
     #[test]
     fn checker_repeat_in_y() {
         let checker = Checker::new(
-            Pattern::SolidColor(SolidColor::new(Color::white())),
-            Pattern::SolidColor(SolidColor::new(Color::black())),
+            Pattern::new(SolidColor::new(Color::white())),
+            Pattern::new(SolidColor::new(Color::black())),
         );
         assert_eq!(checker.pattern_at(&Point::zero()), Color::white());
         assert_eq!(
@@ -53,8 +54,8 @@ mod tests {
     #[test]
     fn checker_repeat_in_z() {
         let checker = Checker::new(
-            Pattern::SolidColor(SolidColor::new(Color::white())),
-            Pattern::SolidColor(SolidColor::new(Color::black())),
+            Pattern::new(SolidColor::new(Color::white())),
+            Pattern::new(SolidColor::new(Color::black())),
         );
         assert_eq!(checker.pattern_at(&Point::zero()), Color::white());
         assert_eq!(
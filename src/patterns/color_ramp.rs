@@ -0,0 +1,40 @@
+use crate::color::*;
+
+// Colors at positions in [0.0, 1.0], blended linearly in between. Noise patterns map their values
+// through one.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ColorRamp {
+    // Sorted by position.
+    pub stops: Vec<(f64, Color)>,
+}
+
+impl ColorRamp {
+    pub fn new(from: Color, to: Color) -> Self {
+        ColorRamp {
+            stops: vec![(0.0, from), (1.0, to)],
+        }
+    }
+
+    pub fn with_stop(mut self, position: f64, color: Color) -> Self {
+        let index = self.stops.partition_point(|(p, _)| *p <= position);
+        self.stops.insert(index, (position, color));
+        self
+    }
+
+    // Color at a position, the first and last colors continue past the ends.
+    pub fn at(&self, position: f64) -> Color {
+        let index = self.stops.partition_point(|(p, _)| *p <= position);
+        match (self.stops.get(index.wrapping_sub(1)), self.stops.get(index)) {
+            (Some((p0, c0)), Some((p1, c1))) => {
+                let fraction = ((position - p0) / (p1 - p0)) as f32;
+                *c0 + (*c1 - *c0) * fraction
+            }
+            (Some((_, color)), None) | (None, Some((_, color))) => *color,
+            (None, None) => Color::black(),
+        }
+    }
+}
+
+#[cfg(test)]
+#[path = "./color_ramp_tests.rs"]
+mod color_ramp_tests;
@@ -0,0 +1,23 @@
+#[cfg(test)]
+mod tests {
+    use crate::patterns::color_ramp::*;
+
+    #[test]
+    fn test_two_colors() {
+        let ramp = ColorRamp::new(Color::black(), Color::white());
+        assert_eq!(ramp.at(0.0), Color::black());
+        assert_eq!(ramp.at(0.25), Color::white() * 0.25);
+        assert_eq!(ramp.at(1.0), Color::white());
+        assert_eq!(ramp.at(-1.0), Color::black());
+        assert_eq!(ramp.at(2.0), Color::white());
+    }
+
+    #[test]
+    fn test_stops() {
+        let ramp = ColorRamp::new(Color::black(), Color::black()).with_stop(0.5, Color::white());
+        assert_eq!(ramp.stops.len(), 3);
+        assert_eq!(ramp.at(0.5), Color::white());
+        assert_eq!(ramp.at(0.75), Color::white() * 0.5);
+        assert_eq!(ramp.at(0.25), Color::white() * 0.5);
+    }
+}
@@ -0,0 +1,172 @@
+use crate::color::*;
+use crate::patterns::color_ramp::*;
+use crate::patterns::*;
+use crate::point::*;
+use noise::{NoiseFn, Perlin};
+use std::f64::consts::PI;
+
+// Weight of a ridged octave per unit of the octave before.
+const RIDGE_WEIGHT: f64 = 2.0;
+
+// How the octaves of Perlin noise add up.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum FractalKind {
+    // Fractional Brownian motion, soft clouds in [-1.0, 1.0].
+    Fbm,
+    // Absolute values of the octaves, billows with sharp creases in [0.0, 1.0].
+    Turbulence,
+    // Musgrave's ridged multifractal, turbulence turned upside down with each octave weighted by
+    // the one before, so detail piles up on the ridges and the valleys stay smooth. Like veins or
+    // mountain crests, in [0.0, 1.0].
+    Ridged,
+}
+
+// Octaves of Perlin noise, each one `lacunarity` times finer and `gain` times weaker than the
+// one before. The sum is divided by the total amplitude so the range does not depend on them.
+#[derive(Clone, Debug)]
+pub struct Fractal {
+    pub kind: FractalKind,
+    pub octaves: u32,
+    pub lacunarity: f64,
+    pub gain: f64,
+    pub noise: Perlin,
+}
+
+impl Fractal {
+    pub fn new(kind: FractalKind) -> Self {
+        Fractal {
+            kind,
+            octaves: 4,
+            lacunarity: 2.0,
+            gain: 0.5,
+            noise: Perlin::default(),
+        }
+    }
+
+    pub fn value(&self, point: &Point) -> f64 {
+        let mut total = 0.0;
+        let mut amplitudes = 0.0;
+        let mut amplitude = 1.0;
+        let mut frequency = 1.0;
+        // Ridged octaves only show where the octave before was high.
+        let mut weight = 1.0;
+        for octave in 0..self.octaves.max(1) {
+            // Perlin noise is 0.0 on whole coordinates, shifting the octaves keeps them from
+            // lining up there.
+            let offset = octave as f64 * 17.31;
+            let n = self.noise.get([
+                point.x * frequency + offset,
+                point.y * frequency + offset,
+                point.z * frequency + offset,
+            ]);
+            total += amplitude
+                * match self.kind {
+                    FractalKind::Fbm => n,
+                    FractalKind::Turbulence => n.abs(),
+                    FractalKind::Ridged => {
+                        let signal = (1.0 - n.abs()).powi(2) * weight;
+                        weight = (signal * RIDGE_WEIGHT).clamp(0.0, 1.0);
+                        signal
+                    }
+                };
+            amplitudes += amplitude;
+            amplitude *= self.gain;
+            frequency *= self.lacunarity;
+        }
+        total / amplitudes
+    }
+
+    // The value moved to [0.0, 1.0].
+    pub fn unit_value(&self, point: &Point) -> f64 {
+        match self.kind {
+            FractalKind::Fbm => 0.5 + 0.5 * self.value(point),
+            _ => self.value(point),
+        }
+        .clamp(0.0, 1.0)
+    }
+}
+
+// The fractal itself, through a color ramp.
+#[derive(Clone, Debug)]
+pub struct FractalNoise {
+    pub fractal: Fractal,
+    pub ramp: ColorRamp,
+}
+
+impl FractalNoise {
+    pub fn new(fractal: Fractal, ramp: ColorRamp) -> Self {
+        FractalNoise { fractal, ramp }
+    }
+}
+
+impl PatternFn for FractalNoise {
+    fn pattern_at(&self, point: &Point) -> Color {
+        self.ramp.at(self.fractal.unit_value(point))
+    }
+}
+
+// Veins across x, `frequency` per unit, bent around by turbulence. The ramp goes from the
+// stone between veins at 0.0 to the middle of the veins at 1.0.
+#[derive(Clone, Debug)]
+pub struct Marble {
+    pub fractal: Fractal,
+    pub ramp: ColorRamp,
+    pub frequency: f64,
+    // How far the turbulence moves the veins, in periods.
+    pub distortion: f64,
+}
+
+impl Marble {
+    pub fn new(ramp: ColorRamp) -> Self {
+        Marble {
+            fractal: Fractal::new(FractalKind::Turbulence),
+            ramp,
+            frequency: 1.0,
+            distortion: 1.5,
+        }
+    }
+}
+
+impl PatternFn for Marble {
+    fn pattern_at(&self, point: &Point) -> Color {
+        let phase = self.frequency * point.x + self.distortion * self.fractal.value(point);
+        self.ramp.at(0.5 - 0.5 * (2.0 * PI * phase).cos())
+    }
+}
+
+// Growth rings around the y axis, `frequency` per unit, made irregular by the fractal. The ramp
+// goes across one ring, from the inside out.
+#[derive(Clone, Debug)]
+pub struct Wood {
+    pub fractal: Fractal,
+    pub ramp: ColorRamp,
+    pub frequency: f64,
+    // How far the fractal moves the rings, in rings.
+    pub distortion: f64,
+}
+
+impl Wood {
+    pub fn new(ramp: ColorRamp) -> Self {
+        Wood {
+            fractal: Fractal {
+                octaves: 2,
+                ..Fractal::new(FractalKind::Fbm)
+            },
+            ramp,
+            frequency: 4.0,
+            distortion: 0.3,
+        }
+    }
+}
+
+impl PatternFn for Wood {
+    fn pattern_at(&self, point: &Point) -> Color {
+        let radius = (point.x * point.x + point.z * point.z).sqrt();
+        let rings = self.frequency * radius + self.distortion * self.fractal.value(point);
+        self.ramp.at(rings - rings.floor())
+    }
+}
+
+#[cfg(test)]
+#[path = "./fractal_tests.rs"]
+mod fractal_tests;
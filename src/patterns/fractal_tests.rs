@@ -0,0 +1,130 @@
+#[cfg(test)]
+mod tests {
+    use crate::patterns::fractal::*;
+    use noise::NoiseFn;
+
+    fn points() -> impl Iterator<Item = Point> {
+        (0..200).map(|i| {
+            let i = i as f64;
+            Point {
+                x: i * 0.173,
+                y: i * 0.071 - 3.0,
+                z: i * 0.297 + 1.5,
+            }
+        })
+    }
+
+    #[test]
+    fn test_single_octave_is_perlin() {
+        let fractal = Fractal {
+            octaves: 1,
+            ..Fractal::new(FractalKind::Fbm)
+        };
+        for p in points() {
+            assert_eq!(fractal.value(&p), fractal.noise.get([p.x, p.y, p.z]));
+        }
+    }
+
+    #[test]
+    fn test_ranges() {
+        for kind in [
+            FractalKind::Fbm,
+            FractalKind::Turbulence,
+            FractalKind::Ridged,
+        ] {
+            let fractal = Fractal::new(kind);
+            for p in points() {
+                let value = fractal.value(&p);
+                let low = if kind == FractalKind::Fbm { -1.0 } else { 0.0 };
+                assert!((low..=1.0).contains(&value), "{:?} gave {}", kind, value);
+                assert!((0.0..=1.0).contains(&fractal.unit_value(&p)));
+            }
+        }
+    }
+
+    #[test]
+    fn test_octaves_add_detail() {
+        let coarse = Fractal {
+            octaves: 1,
+            ..Fractal::new(FractalKind::Turbulence)
+        };
+        let fine = Fractal {
+            octaves: 5,
+            ..coarse.clone()
+        };
+        assert!(points().any(|p| coarse.value(&p) != fine.value(&p)));
+        // Without gain the finer octaves weigh nothing.
+        let flat = Fractal {
+            gain: 0.0,
+            ..fine.clone()
+        };
+        assert!(points().all(|p| flat.value(&p) == coarse.value(&p)));
+    }
+
+    #[test]
+    fn test_ridged_is_inverted_turbulence() {
+        let ridged = Fractal {
+            octaves: 1,
+            ..Fractal::new(FractalKind::Ridged)
+        };
+        let turbulence = Fractal {
+            octaves: 1,
+            ..Fractal::new(FractalKind::Turbulence)
+        };
+        for p in points() {
+            let expected = (1.0 - turbulence.value(&p)).powi(2);
+            assert!((ridged.value(&p) - expected).abs() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn test_ridged_octaves_are_weighted() {
+        let ridged = Fractal::new(FractalKind::Ridged);
+        // The same octaves without the weighting.
+        let unweighted = |p: &Point| {
+            let (mut total, mut amplitudes, mut amplitude, mut frequency) = (0.0, 0.0, 1.0, 1.0);
+            for octave in 0..ridged.octaves {
+                let offset = octave as f64 * 17.31;
+                let n = ridged.noise.get([
+                    p.x * frequency + offset,
+                    p.y * frequency + offset,
+                    p.z * frequency + offset,
+                ]);
+                total += amplitude * (1.0 - n.abs()).powi(2);
+                amplitudes += amplitude;
+                amplitude *= ridged.gain;
+                frequency *= ridged.lacunarity;
+            }
+            total / amplitudes
+        };
+        assert!(points().all(|p| ridged.value(&p) <= unweighted(&p) + 1e-12));
+        // Low ground, where the first octave is weak, loses its detail.
+        assert!(points().any(|p| ridged.value(&p) < unweighted(&p) - 0.01));
+    }
+
+    #[test]
+    fn test_marble_without_distortion() {
+        let marble = Marble {
+            distortion: 0.0,
+            ..Marble::new(ColorRamp::new(Color::black(), Color::white()))
+        };
+        let at = |x: f64| marble.pattern_at(&Point { x, y: 0.3, z: 0.7 });
+        assert_eq!(at(0.0), Color::black());
+        assert_eq!(at(0.5), Color::white());
+        assert_eq!(at(1.0), Color::black());
+    }
+
+    #[test]
+    fn test_wood_rings() {
+        let wood = Wood {
+            distortion: 0.0,
+            frequency: 1.0,
+            ..Wood::new(ColorRamp::new(Color::black(), Color::white()))
+        };
+        let at = |x: f64, z: f64| wood.pattern_at(&Point { x, y: 2.0, z });
+        assert_eq!(at(0.0, 0.0), Color::black());
+        assert_eq!(at(0.25, 0.0), Color::white() * 0.25);
+        assert_eq!(at(0.0, 1.25), Color::white() * 0.25);
+        assert_eq!(at(0.6, 0.8), Color::black());
+    }
+}
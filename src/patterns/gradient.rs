@@ -1,5 +1,4 @@
 use crate::color::*;
-use crate::matrix::*;
 use crate::patterns::*;
 use crate::point::*;
 
@@ -7,7 +6,6 @@ use crate::point::*;
 pub struct Gradient {
     pub pattern_a: Box<Pattern>,
     pub pattern_b: Box<Pattern>,
-    pub transform: Matrix44,
 }
 
 impl Gradient {
@@ -15,15 +13,15 @@ impl Gradient {
         Gradient {
             pattern_a: Box::new(pattern_a),
             pattern_b: Box::new(pattern_b),
-            transform: Matrix44::identity(),
         }
     }
+}
 
-    pub fn pattern_at(&self, point: &Point) -> Color {
-        let pt = self.transform.invert() * *point;
-        let distance = self.pattern_b.pattern_at(&pt) - self.pattern_a.pattern_at(&pt);
-        let fraction = pt.x - pt.x.floor();
-        self.pattern_a.pattern_at(&pt) + distance * fraction as f32
+impl PatternFn for Gradient {
+    fn pattern_at(&self, point: &Point) -> Color {
+        let distance = self.pattern_b.pattern_at(point) - self.pattern_a.pattern_at(point);
+        let fraction = point.x - point.x.floor();
+        self.pattern_a.pattern_at(point) + distance * fraction as f32
     }
 }
 
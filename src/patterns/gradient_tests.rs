@@ -1,12 +1,13 @@
 #[cfg(test)]
 mod tests {
     use crate::patterns::gradient::*;
+    use crate::patterns::solid_color::*;
 
     #[test]
     fn gradient_pattern_linear_interpolation() {
         let g = Gradient::new(
-            Pattern::SolidColor(SolidColor::new(Color::white())),
-            Pattern::SolidColor(SolidColor::new(Color::black())),
+            Pattern::new(SolidColor::new(Color::white())),
+            Pattern::new(SolidColor::new(Color::black())),
         );
         assert_eq!(g.pattern_at(&Point::zero()), Color::white());
         assert_eq!(
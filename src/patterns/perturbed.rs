@@ -1,32 +1,40 @@
 use crate::color::*;
-use crate::matrix::*;
+use crate::patterns::fractal::*;
 use crate::patterns::*;
 use crate::point::*;
-use noise::{Fbm, NoiseFn, Perlin};
 
 #[derive(Clone, Debug)]
 pub struct Perturbed {
     pub pattern: Box<Pattern>,
-    pub transform: Matrix44,
-    // pub noise: Fbm,
-    pub noise: Perlin,
+    pub noise: Fractal,
     pub factor: f64,
 }
 
 impl Perturbed {
+    // Jitters the pattern with a single octave of Perlin noise.
     pub fn new(pattern: Pattern, factor: f64) -> Self {
+        Perturbed::with_noise(
+            pattern,
+            Fractal {
+                octaves: 1,
+                ..Fractal::new(FractalKind::Fbm)
+            },
+            factor,
+        )
+    }
+
+    pub fn with_noise(pattern: Pattern, noise: Fractal, factor: f64) -> Self {
         Perturbed {
             pattern: Box::new(pattern),
-            // noise: Fbm::new(),
-            noise: Perlin::new(),
-            transform: Matrix44::identity(),
+            noise,
             factor,
         }
     }
+}
 
-    pub fn pattern_at(&self, point: &Point) -> Color {
-        let pt = self.transform.invert() * *point;
-        let val = self.noise.get([pt.x, pt.y, pt.z]);
-        self.pattern.pattern_at(&(pt + val * self.factor))
+impl PatternFn for Perturbed {
+    fn pattern_at(&self, point: &Point) -> Color {
+        let val = self.noise.value(point);
+        self.pattern.pattern_at(&(*point + val * self.factor))
     }
 }
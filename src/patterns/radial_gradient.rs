@@ -1,5 +1,4 @@
 use crate::color::*;
-use crate::matrix::*;
 use crate::patterns::*;
 use crate::point::*;
 
@@ -7,7 +6,6 @@ use crate::point::*;
 pub struct RadialGradient {
     pub pattern_a: Box<Pattern>,
     pub pattern_b: Box<Pattern>,
-    pub transform: Matrix44,
 }
 
 impl RadialGradient {
@@ -15,15 +13,16 @@ impl RadialGradient {
         RadialGradient {
             pattern_a: Box::new(pattern_a),
             pattern_b: Box::new(pattern_b),
-            transform: Matrix44::identity(),
         }
     }
+}
 
-    pub fn pattern_at(&self, point: &Point) -> Color {
-        let pt = self.transform.invert() * *point;
-        let distance = (pt.x * pt.x + pt.z * pt.z).sqrt();
+impl PatternFn for RadialGradient {
+    fn pattern_at(&self, point: &Point) -> Color {
+        let distance = (point.x * point.x + point.z * point.z).sqrt();
         let fraction = distance - distance.floor();
-        self.pattern_a.pattern_at(&pt)
-            + (self.pattern_b.pattern_at(&pt) - self.pattern_a.pattern_at(&pt)) * fraction as f32
+        self.pattern_a.pattern_at(point)
+            + (self.pattern_b.pattern_at(point) - self.pattern_a.pattern_at(point))
+                * fraction as f32
     }
 }
@@ -1,5 +1,4 @@
 use crate::color::*;
-use crate::matrix::*;
 use crate::patterns::*;
 use crate::point::*;
 
@@ -7,7 +6,6 @@ use crate::point::*;
 pub struct Ring {
     pub pattern_a: Box<Pattern>,
     pub pattern_b: Box<Pattern>,
-    pub transform: Matrix44,
 }
 
 impl Ring {
@@ -15,16 +13,16 @@ impl Ring {
         Ring {
             pattern_a: Box::new(pattern_a),
             pattern_b: Box::new(pattern_b),
-            transform: Matrix44::identity(),
         }
     }
+}
 
-    pub fn pattern_at(&self, point: &Point) -> Color {
-        let pt = self.transform.invert() * *point;
-        if ((pt.x * pt.x + pt.z * pt.z).sqrt()).floor() % 2.0 == 0.0 {
-            return self.pattern_a.pattern_at(&pt);
+impl PatternFn for Ring {
+    fn pattern_at(&self, point: &Point) -> Color {
+        if ((point.x * point.x + point.z * point.z).sqrt()).floor() % 2.0 == 0.0 {
+            return self.pattern_a.pattern_at(point);
         }
-        self.pattern_b.pattern_at(&pt)
+        self.pattern_b.pattern_at(point)
     }
 }
 
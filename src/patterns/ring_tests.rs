@@ -1,12 +1,13 @@
 #[cfg(test)]
 mod tests {
     use crate::patterns::ring::*;
+    use crate::patterns::solid_color::*;
 
     #[test]
     fn create_stripe_pattern() {
         let s = Ring::new(
-            Pattern::SolidColor(SolidColor::new(Color::white())),
-            Pattern::SolidColor(SolidColor::new(Color::black())),
+            Pattern::new(SolidColor::new(Color::white())),
+            Pattern::new(SolidColor::new(Color::black())),
         );
         assert_eq!(s.pattern_at(&Point::zero()), Color::white());
         assert_eq!(
@@ -1,4 +1,5 @@
 use crate::color::*;
+use crate::patterns::*;
 use crate::point::*;
 
 #[derive(Copy, Clone, Debug)]
@@ -10,8 +11,10 @@ impl SolidColor {
     pub fn new(color: Color) -> Self {
         SolidColor { color }
     }
+}
 
-    pub fn pattern_at(&self, _point: &Point) -> Color {
+impl PatternFn for SolidColor {
+    fn pattern_at(&self, _point: &Point) -> Color {
         self.color
     }
 }
@@ -2,13 +2,14 @@
 mod tests {
     use crate::material::Material;
     use crate::objects::sphere::*;
+    use crate::patterns::solid_color::*;
     use crate::patterns::stripe::*;
 
     #[test]
     fn create_stripe_pattern() {
         let s = Stripe::new(
-            Pattern::SolidColor(SolidColor::new(Color::white())),
-            Pattern::SolidColor(SolidColor::new(Color::black())),
+            Pattern::new(SolidColor::new(Color::white())),
+            Pattern::new(SolidColor::new(Color::black())),
         );
         assert_eq!(s.pattern_a.pattern_at(&Point::zero()), Color::white());
         assert_eq!(s.pattern_b.pattern_at(&Point::zero()), Color::black());
@@ -16,8 +17,8 @@ mod tests {
     #[test]
     fn create_stripe_pattern_constant_y() {
         let s = Stripe::new(
-            Pattern::SolidColor(SolidColor::new(Color::white())),
-            Pattern::SolidColor(SolidColor::new(Color::black())),
+            Pattern::new(SolidColor::new(Color::white())),
+            Pattern::new(SolidColor::new(Color::black())),
         );
         assert_eq!(s.pattern_at(&Point::zero()), Color::white());
         assert_eq!(
@@ -40,8 +41,8 @@ mod tests {
     #[test]
     fn create_stripe_pattern_constant_z() {
         let s = Stripe::new(
-            Pattern::SolidColor(SolidColor::new(Color::white())),
-            Pattern::SolidColor(SolidColor::new(Color::black())),
+            Pattern::new(SolidColor::new(Color::white())),
+            Pattern::new(SolidColor::new(Color::black())),
         );
         assert_eq!(s.pattern_at(&Point::zero()), Color::white());
         assert_eq!(
@@ -64,8 +65,8 @@ mod tests {
     #[test]
     fn create_stripe_pattern_alters_x() {
         let s = Stripe::new(
-            Pattern::SolidColor(SolidColor::new(Color::white())),
-            Pattern::SolidColor(SolidColor::new(Color::black())),
+            Pattern::new(SolidColor::new(Color::white())),
+            Pattern::new(SolidColor::new(Color::black())),
         );
         assert_eq!(s.pattern_at(&Point::zero()), Color::white());
         assert_eq!(
@@ -112,11 +113,11 @@ mod tests {
 
     #[test]
     fn test_stripe_with_object_transformation() {
-        let s = Object::Sphere(Sphere::new(&Material::default()))
+        let s = Object::new(Sphere, Material::default())
             .set_transform(Matrix44::scaling(2.0, 2.0, 2.0));
-        let p = Pattern::Stripe(Stripe::new(
-            Pattern::SolidColor(SolidColor::new(Color::white())),
-            Pattern::SolidColor(SolidColor::new(Color::black())),
+        let p = Pattern::new(Stripe::new(
+            Pattern::new(SolidColor::new(Color::white())),
+            Pattern::new(SolidColor::new(Color::black())),
         ));
         let c = p.pattern_at_object(
             &Point {
@@ -130,10 +131,10 @@ mod tests {
     }
     #[test]
     fn test_stripe_with_pattern_transformation() {
-        let s = Object::Sphere(Sphere::new(&Material::default()));
-        let p = Pattern::Stripe(Stripe::new(
-            Pattern::SolidColor(SolidColor::new(Color::white())),
-            Pattern::SolidColor(SolidColor::new(Color::black())),
+        let s = Object::new(Sphere, Material::default());
+        let p = Pattern::new(Stripe::new(
+            Pattern::new(SolidColor::new(Color::white())),
+            Pattern::new(SolidColor::new(Color::black())),
         ))
         .set_transform(Matrix44::scaling(2.0, 2.0, 2.0));
         let c = p.pattern_at_object(
@@ -148,11 +149,11 @@ mod tests {
     }
     #[test]
     fn test_stripe_with_pattern_and_object_transformation() {
-        let s = Object::Sphere(Sphere::new(&Material::default()))
+        let s = Object::new(Sphere, Material::default())
             .set_transform(Matrix44::scaling(2.0, 2.0, 2.0));
-        let p = Pattern::Stripe(Stripe::new(
-            Pattern::SolidColor(SolidColor::new(Color::white())),
-            Pattern::SolidColor(SolidColor::new(Color::black())),
+        let p = Pattern::new(Stripe::new(
+            Pattern::new(SolidColor::new(Color::white())),
+            Pattern::new(SolidColor::new(Color::black())),
         ))
         .set_transform(Matrix44::translation(0.5, 0.0, 0.0));
         let c = p.pattern_at_object(
@@ -0,0 +1,168 @@
+use crate::color::*;
+use crate::patterns::color_ramp::*;
+use crate::patterns::*;
+use crate::point::*;
+use crate::sampler::{hash, to_unit};
+
+// Which distance to a cell's feature point the pattern shows.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum WorleyOutput {
+    // To the nearest point, round cells dark in the middle.
+    F1,
+    // To the second nearest point.
+    F2,
+    // Difference of the two, 0.0 on the borders between cells, for cracks and scales.
+    F2MinusF1,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Metric {
+    Euclidean,
+    // Sum of the axis distances, cells with diagonal borders.
+    Manhattan,
+    // Largest axis distance, square looking cells.
+    Chebyshev,
+}
+
+impl Metric {
+    pub fn distance(&self, a: &Point, b: &Point) -> f64 {
+        let (x, y, z) = ((a.x - b.x).abs(), (a.y - b.y).abs(), (a.z - b.z).abs());
+        match self {
+            Metric::Euclidean => (x * x + y * y + z * z).sqrt(),
+            Metric::Manhattan => x + y + z,
+            Metric::Chebyshev => x.max(y).max(z),
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub enum CellColor {
+    // The distance output through the ramp.
+    Distance(ColorRamp),
+    // Each cell takes the color of the pattern at its feature point.
+    Pattern(Box<Pattern>),
+    // Each cell gets a color of its own.
+    Random,
+}
+
+// Cellular noise: one feature point in every unit cell, the pattern depends on the distances to
+// the nearest ones.
+#[derive(Clone, Debug)]
+pub struct Worley {
+    pub output: WorleyOutput,
+    pub metric: Metric,
+    // How far feature points stray from the middle of their cell, 0.0 gives a regular grid and
+    // 1.0 anywhere in the cell.
+    pub jitter: f64,
+    pub color: CellColor,
+    // Other seeds give other feature points.
+    pub seed: u32,
+}
+
+impl Worley {
+    pub fn new(color: CellColor) -> Self {
+        Worley {
+            output: WorleyOutput::F1,
+            metric: Metric::Euclidean,
+            jitter: 1.0,
+            color,
+            seed: 0,
+        }
+    }
+
+    fn feature_point(&self, cell: [i64; 3]) -> Point {
+        let offset = |axis: u32| {
+            let bits = hash(&[
+                self.seed,
+                cell[0] as u32,
+                cell[1] as u32,
+                cell[2] as u32,
+                axis,
+            ]);
+            0.5 + self.jitter.clamp(0.0, 1.0) * (to_unit(bits) - 0.5)
+        };
+        Point {
+            x: cell[0] as f64 + offset(0),
+            y: cell[1] as f64 + offset(1),
+            z: cell[2] as f64 + offset(2),
+        }
+    }
+
+    // Distances to the two nearest feature points, and the cell of the nearest one. Cells are
+    // searched in growing shells around the point's own, until the next shell is too far to hold
+    // anything nearer than the second point. Usually that is the 27 cells around the point, but
+    // with a lot of jitter or with the Manhattan metric the nearest points can be further.
+    pub fn nearest(&self, point: &Point) -> (f64, f64, [i64; 3]) {
+        let home = [
+            point.x.floor() as i64,
+            point.y.floor() as i64,
+            point.z.floor() as i64,
+        ];
+        // Closest a feature point gets to the side of its cell.
+        let margin = 0.5 - self.jitter.clamp(0.0, 1.0) / 2.0;
+        let mut f1 = f64::INFINITY;
+        let mut f2 = f64::INFINITY;
+        let mut nearest = home;
+        let mut radius: i64 = 0;
+        loop {
+            for dx in -radius..=radius {
+                for dy in -radius..=radius {
+                    for dz in -radius..=radius {
+                        if dx.abs().max(dy.abs()).max(dz.abs()) != radius {
+                            continue;
+                        }
+                        let cell = [home[0] + dx, home[1] + dy, home[2] + dz];
+                        let distance = self.metric.distance(point, &self.feature_point(cell));
+                        if distance < f1 {
+                            f2 = f1;
+                            f1 = distance;
+                            nearest = cell;
+                        } else if distance < f2 {
+                            f2 = distance;
+                        }
+                    }
+                }
+            }
+            radius += 1;
+            // Points of the next shell are at least this far along one axis, and no metric
+            // makes them any closer.
+            if (radius - 1) as f64 + margin > f2 {
+                return (f1, f2, nearest);
+            }
+        }
+    }
+}
+
+impl PatternFn for Worley {
+    fn pattern_at(&self, point: &Point) -> Color {
+        let (f1, f2, cell) = self.nearest(point);
+        match &self.color {
+            CellColor::Distance(ramp) => ramp.at(match self.output {
+                WorleyOutput::F1 => f1,
+                WorleyOutput::F2 => f2,
+                WorleyOutput::F2MinusF1 => f2 - f1,
+            }),
+            CellColor::Pattern(pattern) => pattern.pattern_at(&self.feature_point(cell)),
+            CellColor::Random => {
+                let channel = |c: u32| {
+                    to_unit(hash(&[
+                        self.seed,
+                        cell[0] as u32,
+                        cell[1] as u32,
+                        cell[2] as u32,
+                        3 + c,
+                    ])) as f32
+                };
+                Color {
+                    red: channel(0),
+                    green: channel(1),
+                    blue: channel(2),
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+#[path = "./worley_tests.rs"]
+mod worley_tests;
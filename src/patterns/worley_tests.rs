@@ -0,0 +1,141 @@
+#[cfg(test)]
+mod tests {
+    use crate::matrix::*;
+    use crate::patterns::worley::*;
+
+    fn point(x: f64, y: f64, z: f64) -> Point {
+        Point { x, y, z }
+    }
+
+    // Feature points in the middle of the cells.
+    fn grid(output: WorleyOutput) -> Worley {
+        Worley {
+            output,
+            jitter: 0.0,
+            ..Worley::new(CellColor::Distance(ColorRamp::new(
+                Color::black(),
+                Color::white(),
+            )))
+        }
+    }
+
+    #[test]
+    fn test_metrics() {
+        let a = point(0.0, 0.0, 0.0);
+        let b = point(1.0, -2.0, 2.0);
+        assert_eq!(Metric::Euclidean.distance(&a, &b), 3.0);
+        assert_eq!(Metric::Manhattan.distance(&a, &b), 5.0);
+        assert_eq!(Metric::Chebyshev.distance(&a, &b), 2.0);
+    }
+
+    #[test]
+    fn test_outputs_on_a_grid() {
+        let f1 = grid(WorleyOutput::F1);
+        assert_eq!(f1.pattern_at(&point(2.5, 0.5, -1.5)), Color::black());
+        assert_eq!(f1.pattern_at(&point(2.0, 0.5, -1.5)), Color::white() * 0.5);
+        // Halfway between two cells both points are as far.
+        assert_eq!(f1.nearest(&point(2.0, 0.5, -1.5)).1, 0.5);
+        let f2 = grid(WorleyOutput::F2);
+        assert_eq!(f2.pattern_at(&point(2.5, 0.5, -1.5)), Color::white());
+        let border = grid(WorleyOutput::F2MinusF1);
+        assert_eq!(border.pattern_at(&point(2.0, 0.5, -1.5)), Color::black());
+        assert_eq!(
+            border.pattern_at(&point(2.25, 0.5, -1.5)),
+            Color::white() * 0.5
+        );
+    }
+
+    #[test]
+    fn test_metric_changes_distances() {
+        let mut worley = grid(WorleyOutput::F1);
+        let p = point(0.2, 0.2, 0.2);
+        assert!((worley.nearest(&p).0 - 0.27_f64.sqrt()).abs() < 1e-12);
+        worley.metric = Metric::Manhattan;
+        assert!((worley.nearest(&p).0 - 0.9).abs() < 1e-12);
+        worley.metric = Metric::Chebyshev;
+        assert!((worley.nearest(&p).0 - 0.3).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_jittered_points() {
+        let worley = Worley::new(CellColor::Random);
+        for i in 0..100 {
+            let i = i as f64;
+            let p = point(i * 0.37, i * -0.11, i * 0.23);
+            let (f1, f2, cell) = worley.nearest(&p);
+            assert!(f1 <= f2);
+            // The nearest point of a cell is at most a cell diagonal away.
+            assert!(f1 <= 3.0_f64.sqrt());
+            assert!((0..3).all(|a| (cell[a] - [p.x, p.y, p.z][a].floor() as i64).abs() <= 1));
+        }
+        let other = Worley {
+            seed: 1,
+            ..worley.clone()
+        };
+        let p = point(0.3, 0.6, 0.9);
+        assert_ne!(worley.nearest(&p).0, other.nearest(&p).0);
+    }
+
+    #[test]
+    fn test_nearest_points_are_exact() {
+        for metric in [Metric::Euclidean, Metric::Manhattan, Metric::Chebyshev] {
+            let worley = Worley {
+                metric,
+                ..Worley::new(CellColor::Random)
+            };
+            for i in 0..300 {
+                let i = i as f64;
+                let p = point(i * 0.137, i * -0.291, i * 0.053);
+                // Every cell that could matter, three cells around.
+                let mut distances = vec![];
+                for dx in -3..=3 {
+                    for dy in -3..=3 {
+                        for dz in -3..=3 {
+                            let feature = worley.feature_point([
+                                p.x.floor() as i64 + dx,
+                                p.y.floor() as i64 + dy,
+                                p.z.floor() as i64 + dz,
+                            ]);
+                            distances.push(metric.distance(&p, &feature));
+                        }
+                    }
+                }
+                distances.sort_by(|a, b| a.partial_cmp(b).unwrap());
+                let (f1, f2, _) = worley.nearest(&p);
+                assert_eq!((f1, f2), (distances[0], distances[1]), "{:?}", metric);
+            }
+        }
+    }
+
+    #[test]
+    fn test_random_cell_colors() {
+        let worley = Worley {
+            jitter: 0.0,
+            ..Worley::new(CellColor::Random)
+        };
+        let a = worley.pattern_at(&point(0.1, 0.2, 0.3));
+        assert_eq!(a, worley.pattern_at(&point(0.9, 0.8, 0.7)));
+        assert_ne!(a, worley.pattern_at(&point(1.1, 0.2, 0.3)));
+        assert_ne!(a, worley.pattern_at(&point(0.1, -0.2, 0.3)));
+    }
+
+    #[test]
+    fn test_cells_colored_by_pattern() {
+        let nested = Pattern::new(TestPattern).set_transform(Matrix44::scaling(2.0, 2.0, 2.0));
+        let worley = Worley {
+            jitter: 0.0,
+            ..Worley::new(CellColor::Pattern(Box::new(nested)))
+        };
+        // The nested pattern is read at the feature point, through its own transform.
+        let expected = Color {
+            red: 0.75,
+            green: 0.25,
+            blue: -0.25,
+        };
+        assert_eq!(worley.pattern_at(&point(1.1, 0.2, -0.7)), expected);
+        assert_eq!(worley.pattern_at(&point(1.9, 0.9, -0.1)), expected);
+        // And the whole pattern goes through its transform.
+        let scaled = Pattern::new(worley).set_transform(Matrix44::scaling(0.5, 0.5, 0.5));
+        assert_eq!(scaled.pattern_at(&point(0.6, 0.1, -0.3)), expected);
+    }
+}
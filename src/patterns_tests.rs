@@ -0,0 +1,62 @@
+#[cfg(test)]
+mod tests {
+    use crate::material::*;
+    use crate::objects::sphere::*;
+    use crate::patterns::blend::*;
+    use crate::patterns::checker::*;
+    use crate::patterns::perturbed::*;
+    use crate::patterns::solid_color::*;
+    use crate::patterns::*;
+
+    // Bands along y, the kind of pattern a user crate would add.
+    #[derive(Debug)]
+    struct Bands {
+        width: f64,
+    }
+
+    impl PatternFn for Bands {
+        fn pattern_at(&self, point: &Point) -> Color {
+            if (point.y / self.width).floor() as i64 % 2 == 0 {
+                Color::white()
+            } else {
+                Color::black()
+            }
+        }
+    }
+
+    fn at(y: f64) -> Point {
+        Point { x: 0.0, y, z: 0.0 }
+    }
+
+    #[test]
+    fn test_custom_pattern_transform() {
+        let bands = Pattern::new(Bands { width: 1.0 });
+        assert_eq!(bands.pattern_at(&at(1.5)), Color::black());
+        // The framework applies the pattern transform, then the object transform.
+        let scaled = bands
+            .clone()
+            .set_transform(Matrix44::scaling(1.0, 2.0, 1.0));
+        assert_eq!(scaled.pattern_at(&at(1.5)), Color::white());
+        let object = Object::new(Sphere, Material::default())
+            .set_transform(Matrix44::scaling(1.0, 2.0, 1.0));
+        assert_eq!(scaled.pattern_at_object(&at(5.0), &object), Color::black());
+    }
+
+    #[test]
+    fn test_custom_pattern_composes() {
+        let bands = Pattern::new(Bands { width: 1.0 });
+        let checker = Pattern::new(Checker::new(
+            bands.clone(),
+            Pattern::new(SolidColor::new(Color::red())),
+        ));
+        assert_eq!(checker.pattern_at(&at(0.5)), Color::white());
+        let blend = Pattern::new(Blend::new(
+            bands.clone(),
+            Pattern::new(SolidColor::new(Color::black())),
+            0.5,
+        ));
+        assert_eq!(blend.pattern_at(&at(0.5)), Color::white() * 0.5);
+        let perturbed = Pattern::new(Perturbed::new(bands, 0.0));
+        assert_eq!(perturbed.pattern_at(&at(1.5)), Color::black());
+    }
+}
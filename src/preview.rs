@@ -0,0 +1,184 @@
+use crate::camera::*;
+use crate::framebuffer::*;
+use crate::render::*;
+use crate::world::*;
+use std::sync::mpsc::{channel, Receiver, Sender, TryRecvError};
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::Instant;
+
+// Block sizes of the low resolution passes shown while the first full pass renders.
+const COARSE_BLOCKS: [u32; 3] = [8, 4, 2];
+// Accumulation stops there, the render thread then waits for the next change.
+const MAX_PREVIEW_SAMPLES: u32 = 256;
+
+// What the window shows, replaced by the render thread after every pass.
+#[derive(Clone, Debug)]
+pub struct PreviewFrame {
+    pub image: Framebuffer,
+    // Full resolution samples averaged in the image, 0 while the coarse passes are shown.
+    pub samples: u32,
+    pub samples_per_second: f64,
+}
+
+impl PreviewFrame {
+    // Window title status.
+    pub fn status(&self) -> String {
+        let speed = format!("{:.2}M samples/s", self.samples_per_second / 1e6);
+        match self.samples {
+            0 => format!("Rustracer - coarse pass - {}", speed),
+            1 => format!("Rustracer - 1 sample - {}", speed),
+            n => format!("Rustracer - {} samples - {}", n, speed),
+        }
+    }
+}
+
+struct Job {
+    cam: Camera,
+    world: Arc<World>,
+    settings: RenderSettings,
+}
+
+// Progressive renderer for the window. A background thread renders coarse passes first, then
+// accumulates jittered full resolution samples until it is restarted or has enough of them.
+pub struct Preview {
+    jobs: Sender<Box<Job>>,
+    frame: Arc<Mutex<Option<PreviewFrame>>>,
+}
+
+impl Preview {
+    pub fn new(cam: Camera, world: Arc<World>, settings: RenderSettings) -> Self {
+        let (jobs, receiver) = channel();
+        let frame = Arc::new(Mutex::new(None));
+        let shared = Arc::clone(&frame);
+        thread::spawn(move || render_thread(receiver, shared));
+        let preview = Preview { jobs, frame };
+        preview.restart(cam, world, settings);
+        preview
+    }
+
+    // Drops the accumulated samples and starts over, after the camera, world or settings changed.
+    pub fn restart(&self, cam: Camera, world: Arc<World>, settings: RenderSettings) {
+        // The thread only stops once the sender is dropped, the send cannot fail before.
+        let _ = self.jobs.send(Box::new(Job {
+            cam,
+            world,
+            settings,
+        }));
+    }
+
+    // Latest pass, if one finished since the last call.
+    pub fn poll(&self) -> Option<PreviewFrame> {
+        self.frame.lock().unwrap().take()
+    }
+}
+
+fn render_thread(jobs: Receiver<Box<Job>>, frame: Arc<Mutex<Option<PreviewFrame>>>) {
+    let mut job = match jobs.recv() {
+        Ok(job) => job,
+        Err(_) => return,
+    };
+    loop {
+        job = match progressive(&job, &jobs, &frame) {
+            Err(Some(next)) => next,
+            Err(None) => return,
+            Ok(()) => match jobs.recv() {
+                Ok(next) => next,
+                Err(_) => return,
+            },
+        };
+    }
+}
+
+// Runs every pass of a job. Stops early with the newest job when one comes in, or with None when
+// the preview is gone.
+fn progressive(
+    job: &Job,
+    jobs: &Receiver<Box<Job>>,
+    frame: &Mutex<Option<PreviewFrame>>,
+) -> Result<(), Option<Box<Job>>> {
+    let publish = |image: Framebuffer, samples: u32, rays: u32, start: Instant| {
+        *frame.lock().unwrap() = Some(PreviewFrame {
+            image,
+            samples,
+            samples_per_second: rays as f64 / start.elapsed().as_secs_f64().max(1e-9),
+        });
+    };
+    for block in COARSE_BLOCKS {
+        let start = Instant::now();
+        let image = trace_pass(job, block, 0, jobs)?;
+        let rays = job.cam.hsize.div_ceil(block) * job.cam.vsize.div_ceil(block);
+        publish(image, 0, rays, start);
+    }
+    let mut sum = Framebuffer::new(job.cam.hsize, job.cam.vsize);
+    for pass in 0..MAX_PREVIEW_SAMPLES {
+        let start = Instant::now();
+        let image = trace_pass(job, 1, pass, jobs)?;
+        for (total, color) in sum.pixels.iter_mut().zip(image.pixels) {
+            *total = *total + color;
+        }
+        let mut average = sum.clone();
+        for color in average.pixels.iter_mut() {
+            *color = *color * (1.0 / (pass + 1) as f32);
+        }
+        publish(average, pass + 1, job.cam.hsize * job.cam.vsize, start);
+    }
+    Ok(())
+}
+
+// One ray per block of `block` x `block` pixels, filling the whole block. Full resolution passes
+// after the first one, which goes through the centers, are spread inside the pixels and over the
+// shutter by the sampler of the settings.
+fn trace_pass(
+    job: &Job,
+    block: u32,
+    pass: u32,
+    jobs: &Receiver<Box<Job>>,
+) -> Result<Framebuffer, Option<Box<Job>>> {
+    let Job {
+        cam,
+        world,
+        settings,
+    } = job;
+    let shutter =
+        |t: f64| settings.shutter_open + (settings.shutter_close - settings.shutter_open) * t;
+    let mut image = Framebuffer::new(cam.hsize, cam.vsize);
+    for by in (0..cam.vsize).step_by(block as usize) {
+        match jobs.try_recv() {
+            Ok(next) => return Err(Some(jobs.try_iter().last().unwrap_or(next))),
+            Err(TryRecvError::Disconnected) => return Err(None),
+            Err(TryRecvError::Empty) => {}
+        }
+        for bx in (0..cam.hsize).step_by(block as usize) {
+            let (x, y, time) = if block == 1 && pass > 0 {
+                let value = |dimension| {
+                    settings
+                        .sampler
+                        .sample(bx, by, pass, MAX_PREVIEW_SAMPLES, dimension)
+                };
+                (
+                    bx as f64 + value(0),
+                    by as f64 + value(1),
+                    shutter(value(2)),
+                )
+            } else {
+                (
+                    bx as f64 + block as f64 / 2.0,
+                    by as f64 + block as f64 / 2.0,
+                    shutter(0.5),
+                )
+            };
+            let color = sample_ray(cam, world, &cam.ray_for_subpixel(x, y, time), settings);
+            for py in by..(by + block).min(cam.vsize) {
+                for px in bx..(bx + block).min(cam.hsize) {
+                    image.set(px, py, color);
+                }
+            }
+        }
+    }
+    Ok(image)
+}
+
+#[cfg(test)]
+#[path = "./preview_tests.rs"]
+mod preview_tests;
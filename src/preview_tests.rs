@@ -0,0 +1,71 @@
+#[cfg(test)]
+mod tests {
+    use crate::color::*;
+    use crate::preview::*;
+    use std::time::Duration;
+
+    // Polls the preview until a frame with at least `samples` samples shows up.
+    fn wait_for(preview: &Preview, samples: u32) -> PreviewFrame {
+        for _ in 0..1000 {
+            if let Some(frame) = preview.poll() {
+                if frame.samples >= samples {
+                    return frame;
+                }
+            }
+            std::thread::sleep(Duration::from_millis(5));
+        }
+        panic!("the preview did not reach {} samples", samples);
+    }
+
+    #[test]
+    fn test_status() {
+        let frame = PreviewFrame {
+            image: Framebuffer::new(1, 1),
+            samples: 12,
+            samples_per_second: 1_500_000.0,
+        };
+        assert_eq!(frame.status(), "Rustracer - 12 samples - 1.50M samples/s");
+    }
+
+    #[test]
+    fn test_accumulates() {
+        let world = Arc::new(World::default());
+        let settings = RenderSettings::default();
+        let preview = Preview::new(
+            Camera::test_camera(16, std::f64::consts::FRAC_PI_2),
+            Arc::clone(&world),
+            settings.clone(),
+        );
+        let frame = wait_for(&preview, 4);
+        let reference = render(
+            &Camera::test_camera(16, std::f64::consts::FRAC_PI_2),
+            &world,
+            &settings,
+        );
+        // Jittered samples average to about the same picture.
+        let center = frame.image.get(8, 8);
+        assert!((center.red - reference.get(8, 8).red).abs() < 0.05);
+        assert_eq!(frame.image.get(0, 0), Color::black());
+    }
+
+    #[test]
+    fn test_restart() {
+        let preview = Preview::new(
+            Camera::test_camera(16, std::f64::consts::FRAC_PI_2),
+            Arc::new(World::default()),
+            RenderSettings::default(),
+        );
+        wait_for(&preview, 2);
+        preview.restart(
+            Camera::test_camera(16, std::f64::consts::FRAC_PI_2),
+            Arc::new(World::new(vec![], vec![])),
+            RenderSettings::default(),
+        );
+        // Frames of the old world may still be around, the new one is empty.
+        let mut frame = wait_for(&preview, 1);
+        while frame.image.get(8, 8) != Color::black() {
+            frame = wait_for(&preview, 1);
+        }
+        assert!(frame.image.pixels.iter().all(|c| *c == Color::black()));
+    }
+}
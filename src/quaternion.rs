@@ -0,0 +1,168 @@
+use crate::matrix::*;
+use std::ops::{Add, Mul, Neg};
+
+#[derive(Copy, Clone, Debug)]
+pub struct Quaternion {
+    pub w: f64,
+    pub x: f64,
+    pub y: f64,
+    pub z: f64,
+}
+
+impl Quaternion {
+    pub fn identity() -> Quaternion {
+        Quaternion {
+            w: 1.0,
+            x: 0.0,
+            y: 0.0,
+            z: 0.0,
+        }
+    }
+
+    // Builds a quaternion from the upper 3x3 part of a pure rotation matrix.
+    pub fn from_rotation(m: &Matrix44) -> Quaternion {
+        let e = &m.elements;
+        let trace = e[0][0] + e[1][1] + e[2][2];
+        let q = if trace > 0.0 {
+            let s = (trace + 1.0).sqrt() * 2.0;
+            Quaternion {
+                w: 0.25 * s,
+                x: (e[2][1] - e[1][2]) / s,
+                y: (e[0][2] - e[2][0]) / s,
+                z: (e[1][0] - e[0][1]) / s,
+            }
+        } else if e[0][0] > e[1][1] && e[0][0] > e[2][2] {
+            let s = (1.0 + e[0][0] - e[1][1] - e[2][2]).sqrt() * 2.0;
+            Quaternion {
+                w: (e[2][1] - e[1][2]) / s,
+                x: 0.25 * s,
+                y: (e[0][1] + e[1][0]) / s,
+                z: (e[0][2] + e[2][0]) / s,
+            }
+        } else if e[1][1] > e[2][2] {
+            let s = (1.0 + e[1][1] - e[0][0] - e[2][2]).sqrt() * 2.0;
+            Quaternion {
+                w: (e[0][2] - e[2][0]) / s,
+                x: (e[0][1] + e[1][0]) / s,
+                y: 0.25 * s,
+                z: (e[1][2] + e[2][1]) / s,
+            }
+        } else {
+            let s = (1.0 + e[2][2] - e[0][0] - e[1][1]).sqrt() * 2.0;
+            Quaternion {
+                w: (e[1][0] - e[0][1]) / s,
+                x: (e[0][2] + e[2][0]) / s,
+                y: (e[1][2] + e[2][1]) / s,
+                z: 0.25 * s,
+            }
+        };
+        q.normalize()
+    }
+
+    pub fn to_rotation(self) -> Matrix44 {
+        let Quaternion { w, x, y, z } = self;
+        Matrix44 {
+            elements: [
+                [
+                    1.0 - 2.0 * (y * y + z * z),
+                    2.0 * (x * y - w * z),
+                    2.0 * (x * z + w * y),
+                    0.0,
+                ],
+                [
+                    2.0 * (x * y + w * z),
+                    1.0 - 2.0 * (x * x + z * z),
+                    2.0 * (y * z - w * x),
+                    0.0,
+                ],
+                [
+                    2.0 * (x * z - w * y),
+                    2.0 * (y * z + w * x),
+                    1.0 - 2.0 * (x * x + y * y),
+                    0.0,
+                ],
+                [0.0, 0.0, 0.0, 1.0],
+            ],
+        }
+    }
+
+    pub fn dot(&self, other: &Quaternion) -> f64 {
+        self.w * other.w + self.x * other.x + self.y * other.y + self.z * other.z
+    }
+
+    pub fn normalize(&self) -> Quaternion {
+        let length = self.dot(self).sqrt();
+        if length > 0.0 {
+            return *self * (1.0 / length);
+        }
+        *self
+    }
+
+    // Spherical linear interpolation, always following the shortest arc.
+    pub fn slerp(&self, other: &Quaternion, t: f64) -> Quaternion {
+        let mut end = *other;
+        let mut cos = self.dot(other);
+        if cos < 0.0 {
+            end = -end;
+            cos = -cos;
+        }
+        if cos > 0.9995 {
+            return (*self * (1.0 - t) + end * t).normalize();
+        }
+        let theta = cos.acos();
+        let sin = theta.sin();
+        *self * (((1.0 - t) * theta).sin() / sin) + end * ((t * theta).sin() / sin)
+    }
+}
+
+impl Add for Quaternion {
+    type Output = Quaternion;
+
+    fn add(self, other: Quaternion) -> Quaternion {
+        Quaternion {
+            w: self.w + other.w,
+            x: self.x + other.x,
+            y: self.y + other.y,
+            z: self.z + other.z,
+        }
+    }
+}
+
+impl Mul<f64> for Quaternion {
+    type Output = Quaternion;
+
+    fn mul(self, other: f64) -> Quaternion {
+        Quaternion {
+            w: self.w * other,
+            x: self.x * other,
+            y: self.y * other,
+            z: self.z * other,
+        }
+    }
+}
+
+impl Neg for Quaternion {
+    type Output = Quaternion;
+
+    fn neg(self) -> Quaternion {
+        Quaternion {
+            w: -self.w,
+            x: -self.x,
+            y: -self.y,
+            z: -self.z,
+        }
+    }
+}
+
+impl PartialEq for Quaternion {
+    fn eq(&self, other: &Self) -> bool {
+        (self.w - other.w).abs() < 1e-12
+            && (self.x - other.x).abs() < 1e-12
+            && (self.y - other.y).abs() < 1e-12
+            && (self.z - other.z).abs() < 1e-12
+    }
+}
+
+#[cfg(test)]
+#[path = "./quaternion_tests.rs"]
+mod quaternion_tests;
@@ -0,0 +1,44 @@
+#[cfg(test)]
+mod tests {
+    use crate::quaternion::*;
+
+    #[test]
+    fn test_identity_rotation() {
+        assert_eq!(
+            Quaternion::from_rotation(&Matrix44::identity()),
+            Quaternion::identity()
+        );
+        assert_eq!(Quaternion::identity().to_rotation(), Matrix44::identity());
+    }
+
+    #[test]
+    fn test_rotation_round_trip() {
+        let rotations = vec![
+            Matrix44::rotation_x(0.3),
+            Matrix44::rotation_y(-2.0),
+            Matrix44::rotation_z(std::f64::consts::PI),
+            Matrix44::rotation_x(1.0).rotate_y(2.5).rotate_z(-0.4),
+        ];
+        for rotation in rotations {
+            let q = Quaternion::from_rotation(&rotation);
+            let m = q.to_rotation();
+            for i in 0..=3 {
+                for j in 0..=3 {
+                    assert!((m.elements[i][j] - rotation.elements[i][j]).abs() < 1e-12);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_slerp_halfway() {
+        let a = Quaternion::from_rotation(&Matrix44::rotation_y(0.0));
+        let b = Quaternion::from_rotation(&Matrix44::rotation_y(std::f64::consts::FRAC_PI_2));
+        assert_eq!(
+            a.slerp(&b, 0.5),
+            Quaternion::from_rotation(&Matrix44::rotation_y(std::f64::consts::FRAC_PI_4))
+        );
+        assert_eq!(a.slerp(&b, 0.0), a);
+        assert_eq!(a.slerp(&b, 1.0), b);
+    }
+}
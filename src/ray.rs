@@ -3,9 +3,12 @@ use crate::point::*;
 use crate::vector3::*;
 use std::fmt::Display;
 
+#[derive(Clone, Copy, Debug, PartialEq)]
 pub struct Ray {
     pub origin: Point,
     pub direction: Vector3,
+    // Instant within the shutter interval the ray was emitted at, see Motion.
+    pub time: f64,
 }
 
 impl Ray {
@@ -16,7 +19,11 @@ impl Ray {
     pub fn transform(&self, transform: Matrix44) -> Ray {
         let origin = self.origin * transform;
         let direction = self.direction * transform;
-        Ray { origin, direction }
+        Ray {
+            origin,
+            direction,
+            time: self.time,
+        }
     }
 }
 
@@ -24,8 +31,8 @@ impl Display for Ray {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(
             f,
-            "Ray {{ origin: {}, direction: {} }}",
-            self.origin, self.direction
+            "Ray {{ origin: {}, direction: {}, time: {} }}",
+            self.origin, self.direction, self.time
         )
     }
 }
@@ -14,7 +14,11 @@ mod tests {
             y: 5.0,
             z: 6.0,
         };
-        let ray = Ray { origin, direction };
+        let ray = Ray {
+            origin,
+            direction,
+            time: 0.0,
+        };
         assert_eq!(ray.direction, direction);
         assert_eq!(ray.origin, origin);
     }
@@ -31,6 +35,7 @@ mod tests {
                 y: 0.0,
                 z: 0.0,
             },
+            time: 0.0,
         };
         assert_eq!(
             ray.position(0.0),
@@ -79,6 +84,7 @@ mod tests {
                 y: 1.0,
                 z: 0.0,
             },
+            time: 0.0,
         };
         let translation = Matrix44::translation(3.0, 4.0, 5.0);
         let transformed_ray = ray.transform(translation);
@@ -113,6 +119,7 @@ mod tests {
                 y: 1.0,
                 z: 0.0,
             },
+            time: 0.0,
         };
         let scaling = Matrix44::scaling(2.0, 3.0, 4.0);
         let transformed_ray = ray.transform(scaling);
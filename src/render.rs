@@ -0,0 +1,119 @@
+use crate::aov::*;
+use crate::camera::*;
+use crate::color::*;
+use crate::debug_view::*;
+use crate::denoise::*;
+use crate::framebuffer::*;
+use crate::ray::*;
+use crate::sampler::*;
+use crate::stats;
+use crate::tonemap::*;
+use crate::world::*;
+
+#[derive(Clone, Debug)]
+pub struct RenderSettings {
+    pub max_recursion: i32,
+    pub samples: u32,
+    // Shutter interval rays are spread over, within [0.0, 1.0] where 0.0 is the start transform
+    // of moving objects and 1.0 their end transform.
+    pub shutter_open: f64,
+    pub shutter_close: f64,
+    pub post_process: PostProcess,
+    // Filters the render guided by its normal, depth and albedo passes, for low sample counts.
+    pub denoiser: Option<Denoiser>,
+    pub view: DebugView,
+    // Where in the pixel and when in the shutter interval samples go, past the first one.
+    pub sampler: Sampler,
+}
+
+impl Default for RenderSettings {
+    fn default() -> Self {
+        RenderSettings {
+            max_recursion: 5,
+            samples: 1,
+            shutter_open: 0.0,
+            shutter_close: 1.0,
+            post_process: PostProcess::default(),
+            denoiser: None,
+            view: DebugView::Beauty,
+            sampler: Sampler::default(),
+        }
+    }
+}
+
+impl RenderSettings {
+    // Stratified sample time, the shutter interval is split in one slot per sample.
+    pub fn sample_time(&self, sample: u32) -> f64 {
+        let fraction = (sample as f64 + 0.5) / self.samples.max(1) as f64;
+        self.shutter_open + (self.shutter_close - self.shutter_open) * fraction
+    }
+
+    // Camera ray of a sample of the pixel. A single sample goes through the center of the pixel in
+    // the middle of the shutter interval, more are spread by the sampler.
+    pub fn camera_ray(&self, cam: &Camera, x: u32, y: u32, sample: u32) -> Ray {
+        if self.samples <= 1 {
+            return cam.ray_for_pixel_at(x, y, self.sample_time(sample));
+        }
+        let value = |dimension| self.sampler.sample(x, y, sample, self.samples, dimension);
+        let time = self.shutter_open + (self.shutter_close - self.shutter_open) * value(2);
+        cam.ray_for_subpixel(x as f64 + value(0), y as f64 + value(1), time)
+    }
+
+    // Post process for the output, debug views are shown as they are.
+    pub fn display(&self) -> PostProcess {
+        match self.view {
+            DebugView::Beauty => self.post_process,
+            _ => PostProcess {
+                dither: self.post_process.dither,
+                ..PostProcess::default()
+            },
+        }
+    }
+}
+
+// Color seen along a camera ray, in the debug view picked by the settings.
+pub fn sample_ray(cam: &Camera, world: &World, ray: &Ray, settings: &RenderSettings) -> Color {
+    settings.view.color(cam, world, ray, settings.max_recursion)
+}
+
+// Averages every sample of a pixel, each one taken at a different instant of the shutter interval.
+pub fn pixel_color(
+    cam: &Camera,
+    world: &World,
+    x: u32,
+    y: u32,
+    settings: &RenderSettings,
+) -> Color {
+    let samples = settings.samples.max(1);
+    let sum = (0..samples).fold(Color::black(), |sum, sample| {
+        let ray = settings.camera_ray(cam, x, y, sample);
+        sum + stats::primary(|| sample_ray(cam, world, &ray, settings))
+    });
+    sum * (1.0 / samples as f32)
+}
+
+// Renders the whole camera view into a linear framebuffer.
+pub fn render(cam: &Camera, world: &World, settings: &RenderSettings) -> Framebuffer {
+    if settings.denoiser.is_some() && settings.view == DebugView::Beauty {
+        return beauty(&render_aovs(cam, world, settings), settings);
+    }
+    let mut framebuffer = Framebuffer::new(cam.hsize, cam.vsize);
+    for y in 0..cam.vsize {
+        for x in 0..cam.hsize {
+            framebuffer.set(x, y, pixel_color(cam, world, x, y, settings));
+        }
+    }
+    framebuffer
+}
+
+// Beauty image of a render done with passes, denoised when the settings ask for it.
+pub fn beauty(aovs: &Aovs, settings: &RenderSettings) -> Framebuffer {
+    match &settings.denoiser {
+        Some(denoiser) => denoiser.denoise(&aovs.beauty(), aovs),
+        None => aovs.beauty(),
+    }
+}
+
+#[cfg(test)]
+#[path = "./render_tests.rs"]
+mod render_tests;
@@ -0,0 +1,61 @@
+#[cfg(test)]
+mod tests {
+    use crate::material::*;
+    use crate::matrix::*;
+    use crate::motion::*;
+    use crate::objects::sphere::*;
+    use crate::objects::*;
+    use crate::render::*;
+
+    #[test]
+    fn test_sample_times_are_stratified() {
+        let settings = RenderSettings {
+            samples: 4,
+            shutter_open: 0.0,
+            shutter_close: 0.5,
+            ..RenderSettings::default()
+        };
+        let times: Vec<f64> = (0..4).map(|s| settings.sample_time(s)).collect();
+        assert_eq!(times, vec![0.0625, 0.1875, 0.3125, 0.4375]);
+    }
+
+    #[test]
+    fn test_motion_blur_averages_samples() {
+        let world = World::new(
+            vec![Object::new(Sphere, Material::default())
+                .set_motion(Matrix44::translation(0.0, 5.0, 0.0), Interpolation::Linear)],
+            World::default().lights,
+        );
+        let cam = Camera::test_camera(11, std::f64::consts::FRAC_PI_2);
+        let still = RenderSettings {
+            shutter_open: 0.0,
+            shutter_close: 0.0,
+            ..RenderSettings::default()
+        };
+        let blurred = RenderSettings {
+            samples: 10,
+            ..RenderSettings::default()
+        };
+        let hit = pixel_color(&cam, &world, 5, 5, &still);
+        let blur = pixel_color(&cam, &world, 5, 5, &blurred);
+        assert_ne!(hit, Color::black());
+        assert!(blur.red < hit.red && blur.red > 0.0);
+    }
+
+    #[test]
+    fn test_samples_are_reproducible() {
+        let world = World::default();
+        let cam = Camera::test_camera(9, std::f64::consts::FRAC_PI_2);
+        let settings = |kind: SamplerKind, seed: u32| RenderSettings {
+            samples: 4,
+            sampler: Sampler::new(kind, seed),
+            ..RenderSettings::default()
+        };
+        for kind in SamplerKind::all() {
+            let image = render(&cam, &world, &settings(kind, 1)).pixels;
+            assert_eq!(image, render(&cam, &world, &settings(kind, 1)).pixels);
+            // The edge of the sphere is only partly covered by the jittered samples.
+            assert_ne!(image, render(&cam, &world, &settings(kind, 2)).pixels);
+        }
+    }
+}
@@ -0,0 +1,143 @@
+use crate::camera::*;
+use crate::matrix::*;
+use crate::point::*;
+use crate::vector3::*;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum StereoLayout {
+    SideBySide,
+    OverUnder,
+}
+
+impl StereoLayout {
+    // Size of the combined image holding both eyes.
+    pub fn dimensions(&self, hsize: u32, vsize: u32) -> (u32, u32) {
+        match self {
+            StereoLayout::SideBySide => (hsize * 2, vsize),
+            StereoLayout::OverUnder => (hsize, vsize * 2),
+        }
+    }
+
+    // Top left corner of the right eye in the combined image, the left eye always sits at (0, 0).
+    pub fn right_eye_offset(&self, hsize: u32, vsize: u32) -> (u32, u32) {
+        match self {
+            StereoLayout::SideBySide => (hsize, 0),
+            StereoLayout::OverUnder => (0, vsize),
+        }
+    }
+}
+
+pub struct StereoRig {
+    pub interocular: f64,
+    // Distance from the eyes to the zero parallax plane, infinity gives parallel eyes.
+    pub convergence: f64,
+    pub layout: StereoLayout,
+}
+
+impl Default for StereoRig {
+    fn default() -> Self {
+        StereoRig {
+            interocular: 0.065,
+            convergence: 5.0,
+            layout: StereoLayout::SideBySide,
+        }
+    }
+}
+
+impl StereoRig {
+    // Builds the (left, right) eye cameras around a center camera, both eyes toed-in on the convergence point.
+    pub fn eyes(&self, camera: &Camera) -> (Camera, Camera) {
+        let position = camera.position();
+        let forward = camera.forward();
+        let up = camera.up();
+        let left = forward.cross(&up).normalize();
+        let half = left * (self.interocular / 2.0);
+        let eye = |from: Point| {
+            let to = if self.convergence.is_finite() {
+                position + forward * self.convergence
+            } else {
+                from + forward
+            };
+            let mut cam = Camera::new(camera.hsize, camera.vsize, camera.field_of_view);
+            cam.set_transform(view_transform(from, to, up));
+            cam
+        };
+        (eye(position + half), eye(position - half))
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum CubeFace {
+    PositiveX,
+    NegativeX,
+    PositiveY,
+    NegativeY,
+    PositiveZ,
+    NegativeZ,
+}
+
+impl CubeFace {
+    pub fn all() -> [CubeFace; 6] {
+        [
+            CubeFace::PositiveX,
+            CubeFace::NegativeX,
+            CubeFace::PositiveY,
+            CubeFace::NegativeY,
+            CubeFace::PositiveZ,
+            CubeFace::NegativeZ,
+        ]
+    }
+
+    // Short name used as file suffix, e.g. render_px.png
+    pub fn name(&self) -> &'static str {
+        match self {
+            CubeFace::PositiveX => "px",
+            CubeFace::NegativeX => "nx",
+            CubeFace::PositiveY => "py",
+            CubeFace::NegativeY => "ny",
+            CubeFace::PositiveZ => "pz",
+            CubeFace::NegativeZ => "nz",
+        }
+    }
+
+    // (direction, up) pair the face camera is oriented with.
+    pub fn orientation(&self) -> (Vector3, Vector3) {
+        let axis = |x: f64, y: f64, z: f64| Vector3 { x, y, z };
+        match self {
+            CubeFace::PositiveX => (axis(1.0, 0.0, 0.0), axis(0.0, 1.0, 0.0)),
+            CubeFace::NegativeX => (axis(-1.0, 0.0, 0.0), axis(0.0, 1.0, 0.0)),
+            CubeFace::PositiveY => (axis(0.0, 1.0, 0.0), axis(0.0, 0.0, -1.0)),
+            CubeFace::NegativeY => (axis(0.0, -1.0, 0.0), axis(0.0, 0.0, 1.0)),
+            CubeFace::PositiveZ => (axis(0.0, 0.0, 1.0), axis(0.0, 1.0, 0.0)),
+            CubeFace::NegativeZ => (axis(0.0, 0.0, -1.0), axis(0.0, 1.0, 0.0)),
+        }
+    }
+}
+
+pub struct CubemapRig {
+    pub position: Point,
+    pub size: u32,
+}
+
+impl CubemapRig {
+    pub fn new(position: Point, size: u32) -> Self {
+        CubemapRig { position, size }
+    }
+
+    // Six square cameras with a 90 degrees field of view, one per cube face.
+    pub fn faces(&self) -> Vec<(CubeFace, Camera)> {
+        CubeFace::all()
+            .iter()
+            .map(|face| {
+                let (direction, up) = face.orientation();
+                let mut cam = Camera::new(self.size, self.size, std::f64::consts::FRAC_PI_2);
+                cam.set_transform(view_transform(self.position, self.position + direction, up));
+                (*face, cam)
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+#[path = "./rig_tests.rs"]
+mod rig_tests;
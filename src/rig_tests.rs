@@ -0,0 +1,96 @@
+#[cfg(test)]
+mod tests {
+    use crate::matrix::*;
+    use crate::rig::*;
+
+    fn center_camera() -> Camera {
+        let mut cam = Camera::new(101, 101, std::f64::consts::FRAC_PI_3);
+        cam.set_transform(view_transform(
+            Point {
+                x: 0.0,
+                y: 1.0,
+                z: -5.0,
+            },
+            Point {
+                x: 0.0,
+                y: 1.0,
+                z: 0.0,
+            },
+            Vector3 {
+                x: 0.0,
+                y: 1.0,
+                z: 0.0,
+            },
+        ));
+        cam
+    }
+
+    #[test]
+    fn stereo_eyes_are_interocular_apart() {
+        let rig = StereoRig {
+            interocular: 0.5,
+            ..StereoRig::default()
+        };
+        let (left, right) = rig.eyes(&center_camera());
+        let distance = (left.position() - right.position()).length();
+        assert!((distance - 0.5).abs() < 1e-9);
+        assert!(left.position().x < right.position().x);
+    }
+
+    #[test]
+    fn stereo_eyes_converge() {
+        let rig = StereoRig {
+            interocular: 0.5,
+            convergence: 5.0,
+            layout: StereoLayout::OverUnder,
+        };
+        let (left, right) = rig.eyes(&center_camera());
+        let target = Point {
+            x: 0.0,
+            y: 1.0,
+            z: 0.0,
+        };
+        for eye in [left, right] {
+            let ray = eye.ray_for_pixel(50, 50);
+            let expected = (target - ray.origin).normalize();
+            assert!((ray.direction - expected).length() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn stereo_eyes_parallel_without_convergence() {
+        let rig = StereoRig {
+            convergence: f64::INFINITY,
+            ..StereoRig::default()
+        };
+        let (left, right) = rig.eyes(&center_camera());
+        assert!((left.forward() - right.forward()).length() < 1e-9);
+    }
+
+    #[test]
+    fn stereo_layout_dimensions() {
+        assert_eq!(StereoLayout::SideBySide.dimensions(40, 30), (80, 30));
+        assert_eq!(StereoLayout::OverUnder.dimensions(40, 30), (40, 60));
+        assert_eq!(StereoLayout::SideBySide.right_eye_offset(40, 30), (40, 0));
+        assert_eq!(StereoLayout::OverUnder.right_eye_offset(40, 30), (0, 30));
+    }
+
+    #[test]
+    fn cubemap_faces_look_along_axes() {
+        let position = Point {
+            x: 1.0,
+            y: 2.0,
+            z: 3.0,
+        };
+        let rig = CubemapRig::new(position, 64);
+        let faces = rig.faces();
+        assert_eq!(faces.len(), 6);
+        for (face, cam) in faces {
+            let (direction, _) = face.orientation();
+            assert_eq!(cam.hsize, 64);
+            assert_eq!(cam.vsize, 64);
+            assert!((cam.position() - position).length() < 1e-9);
+            assert!((cam.forward() - direction).length() < 1e-9);
+        }
+    }
+}
@@ -0,0 +1,220 @@
+// Sample points for everything a render spreads over several samples: positions inside the
+// pixel, instants of the shutter interval, and whatever else needs random numbers.
+//
+// Samplers have no state, a value only depends on the seed, the pixel, the sample index and the
+// dimension, so a render comes out the same whatever the order or the thread pixels are traced
+// on. Each pixel gets its own scrambling of the sequence, which keeps neighbouring pixels from
+// sharing the same pattern. Camera rays use dimensions 0 and 1 for the position in the pixel and
+// 2 for the time.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum SamplerKind {
+    // Independent uniform values.
+    Random,
+    // Each dimension split in one stratum per sample, visited in a shuffled order.
+    Stratified,
+    // Radical inverses in prime bases, randomly rotated per pixel.
+    Halton,
+    // Sobol points, randomly digit scrambled per pixel. Dimensions past the built in direction
+    // numbers fall back to random values.
+    Sobol,
+}
+
+impl SamplerKind {
+    pub fn all() -> [SamplerKind; 4] {
+        [
+            SamplerKind::Random,
+            SamplerKind::Stratified,
+            SamplerKind::Halton,
+            SamplerKind::Sobol,
+        ]
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            SamplerKind::Random => "random",
+            SamplerKind::Stratified => "stratified",
+            SamplerKind::Halton => "halton",
+            SamplerKind::Sobol => "sobol",
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<SamplerKind> {
+        SamplerKind::all()
+            .into_iter()
+            .find(|kind| kind.name() == name)
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Sampler {
+    pub kind: SamplerKind,
+    // Renders with different seeds get different noise.
+    pub seed: u32,
+}
+
+impl Default for Sampler {
+    fn default() -> Self {
+        Sampler {
+            kind: SamplerKind::Stratified,
+            seed: 0,
+        }
+    }
+}
+
+impl Sampler {
+    pub fn new(kind: SamplerKind, seed: u32) -> Self {
+        Sampler { kind, seed }
+    }
+
+    // Value in [0.0, 1.0) for one dimension of a sample of the pixel, out of `samples` samples.
+    pub fn sample(&self, x: u32, y: u32, index: u32, samples: u32, dimension: u32) -> f64 {
+        let scramble = hash(&[self.seed, x, y, dimension]);
+        match self.kind {
+            SamplerKind::Random => to_unit(hash(&[self.seed, x, y, dimension, index])),
+            SamplerKind::Stratified => {
+                let samples = samples.max(1);
+                // Past the sample count, start again with another shuffle.
+                let round = hash(&[scramble, index / samples]);
+                let stratum = permute(index % samples, samples, round);
+                let jitter = to_unit(hash(&[round, index]));
+                (stratum as f64 + jitter) / samples as f64
+            }
+            SamplerKind::Halton => match PRIMES.get(dimension as usize) {
+                Some(base) => (halton(index, *base) + to_unit(scramble)).fract(),
+                None => {
+                    Sampler::new(SamplerKind::Random, self.seed).sample(x, y, index, 0, dimension)
+                }
+            },
+            SamplerKind::Sobol => match sobol(index, dimension) {
+                Some(bits) => to_unit(bits ^ scramble),
+                None => {
+                    Sampler::new(SamplerKind::Random, self.seed).sample(x, y, index, 0, dimension)
+                }
+            },
+        }
+    }
+}
+
+// Bases of the Halton dimensions.
+const PRIMES: [u32; 16] = [2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37, 41, 43, 47, 53];
+
+// Radical inverse of the index in the given base, a low discrepancy sequence in [0.0, 1.0).
+pub fn halton(mut index: u32, base: u32) -> f64 {
+    let mut result = 0.0;
+    let mut fraction = 1.0 / base as f64;
+    while index > 0 {
+        result += (index % base) as f64 * fraction;
+        index /= base;
+        fraction /= base as f64;
+    }
+    result
+}
+
+// Primitive polynomials and initial direction numbers of Sobol dimensions 1 and up, from Joe and
+// Kuo: degree, coefficients and the m values. Dimension 0 is the van der Corput sequence.
+const SOBOL_POLYNOMIALS: [(u32, u32, [u32; 5]); 9] = [
+    (1, 0, [1, 0, 0, 0, 0]),
+    (2, 1, [1, 3, 0, 0, 0]),
+    (3, 1, [1, 3, 1, 0, 0]),
+    (3, 2, [1, 1, 1, 0, 0]),
+    (4, 1, [1, 1, 3, 3, 0]),
+    (4, 4, [1, 3, 5, 13, 0]),
+    (5, 2, [1, 1, 5, 5, 17]),
+    (5, 4, [1, 1, 5, 5, 5]),
+    (5, 7, [1, 1, 7, 11, 19]),
+];
+
+fn direction_numbers(dimension: u32) -> Option<[u32; 32]> {
+    let mut v = [0; 32];
+    if dimension == 0 {
+        for (bit, v) in v.iter_mut().enumerate() {
+            *v = 1 << (31 - bit);
+        }
+        return Some(v);
+    }
+    let (degree, coefficients, m) = SOBOL_POLYNOMIALS.get(dimension as usize - 1)?;
+    let s = *degree as usize;
+    for bit in 0..32 {
+        v[bit] = if bit < s {
+            m[bit] << (31 - bit)
+        } else {
+            let mut value = v[bit - s] ^ (v[bit - s] >> s);
+            for k in 1..s {
+                if (coefficients >> (s - 1 - k)) & 1 == 1 {
+                    value ^= v[bit - k];
+                }
+            }
+            value
+        };
+    }
+    Some(v)
+}
+
+// Bits of the Sobol point of the index in a dimension, as a fraction of 2^32. None past the
+// dimensions there are direction numbers for.
+pub fn sobol(index: u32, dimension: u32) -> Option<u32> {
+    let v = direction_numbers(dimension)?;
+    Some(
+        (0..32)
+            .filter(|bit| (index >> bit) & 1 == 1)
+            .fold(0, |bits, bit| bits ^ v[bit as usize]),
+    )
+}
+
+// Mixes the values into well spread out bits, the same values always give the same bits.
+pub fn hash(values: &[u32]) -> u32 {
+    let mut h: u32 = 0x9e37_79b9;
+    for value in values {
+        h ^= value.wrapping_add(0x7f4a_7c15);
+        h = h.wrapping_mul(0x85eb_ca6b);
+        h ^= h >> 13;
+        h = h.wrapping_mul(0xc2b2_ae35);
+        h ^= h >> 16;
+    }
+    h
+}
+
+// Bits as a fraction in [0.0, 1.0).
+pub fn to_unit(bits: u32) -> f64 {
+    bits as f64 / 4_294_967_296.0
+}
+
+// Shuffles [0, length) without repeating a value, a different order for every pattern. From
+// Kensler, "Correlated Multi-Jittered Sampling".
+fn permute(mut i: u32, length: u32, pattern: u32) -> u32 {
+    let p = pattern;
+    let mut w = length - 1;
+    w |= w >> 1;
+    w |= w >> 2;
+    w |= w >> 4;
+    w |= w >> 8;
+    w |= w >> 16;
+    loop {
+        i ^= p;
+        i = i.wrapping_mul(0xe170_893d);
+        i ^= p >> 16;
+        i ^= (i & w) >> 4;
+        i ^= p >> 8;
+        i = i.wrapping_mul(0x0929_eb3f);
+        i ^= p >> 23;
+        i ^= (i & w) >> 1;
+        i = i.wrapping_mul(1 | p >> 27);
+        i = i.wrapping_mul(0x6935_fa69);
+        i ^= (i & w) >> 11;
+        i = i.wrapping_mul(0x74dc_b303);
+        i ^= (i & w) >> 2;
+        i = i.wrapping_mul(0x9e50_1cc3);
+        i ^= (i & w) >> 2;
+        i = i.wrapping_mul(0xc860_a3df);
+        i &= w;
+        i ^= i >> 5;
+        if i < length {
+            break;
+        }
+    }
+    (i + p % length) % length
+}
+
+#[cfg(test)]
+#[path = "./sampler_tests.rs"]
+mod sampler_tests;
@@ -0,0 +1,95 @@
+#[cfg(test)]
+mod tests {
+    use crate::sampler::*;
+
+    // Which of `count` equal intervals of [0.0, 1.0) each value falls in.
+    fn strata(values: &[f64], count: u32) -> Vec<u32> {
+        let mut strata: Vec<u32> = values.iter().map(|v| (v * count as f64) as u32).collect();
+        strata.sort();
+        strata
+    }
+
+    #[test]
+    fn test_halton() {
+        let values: Vec<f64> = (1..5).map(|i| halton(i, 2)).collect();
+        assert_eq!(values, vec![0.5, 0.25, 0.75, 0.125]);
+        assert!((halton(1, 3) - 1.0 / 3.0).abs() < 1e-12);
+        assert!((0..100).all(|i| (0.0..1.0).contains(&halton(i, 5))));
+    }
+
+    #[test]
+    fn test_sobol_first_points() {
+        let point = |i: u32, d: u32| sobol(i, d).unwrap() as f64 / 4_294_967_296.0;
+        let first: Vec<f64> = (0..8).map(|i| point(i, 0)).collect();
+        assert_eq!(
+            first,
+            vec![0.0, 0.5, 0.25, 0.75, 0.125, 0.625, 0.375, 0.875]
+        );
+        let second: Vec<f64> = (0..8).map(|i| point(i, 1)).collect();
+        assert_eq!(
+            second,
+            vec![0.0, 0.5, 0.75, 0.25, 0.625, 0.125, 0.375, 0.875]
+        );
+        assert_eq!(sobol(1, 100), None);
+    }
+
+    #[test]
+    fn test_sobol_dimensions_are_stratified() {
+        // Any 2^k consecutive points from 0 put one point in each of 2^k intervals.
+        for dimension in 0..10 {
+            let values: Vec<f64> = (0..64)
+                .map(|i| sobol(i, dimension).unwrap() as f64 / 4_294_967_296.0)
+                .collect();
+            assert_eq!(strata(&values, 64), (0..64).collect::<Vec<u32>>());
+        }
+    }
+
+    #[test]
+    fn test_stratified_covers_every_stratum() {
+        let sampler = Sampler::new(SamplerKind::Stratified, 7);
+        for samples in [1, 5, 16, 33] {
+            for dimension in 0..3 {
+                let values: Vec<f64> = (0..samples)
+                    .map(|i| sampler.sample(3, 4, i, samples, dimension))
+                    .collect();
+                assert_eq!(strata(&values, samples), (0..samples).collect::<Vec<u32>>());
+            }
+        }
+    }
+
+    #[test]
+    fn test_values_in_unit_interval() {
+        for kind in SamplerKind::all() {
+            let sampler = Sampler::new(kind, 1);
+            for index in 0..64 {
+                for dimension in 0..20 {
+                    let value = sampler.sample(9, 2, index, 64, dimension);
+                    assert!((0.0..1.0).contains(&value), "{:?} gave {}", kind, value);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_reproducible_and_decorrelated() {
+        for kind in SamplerKind::all() {
+            let sampler = Sampler::new(kind, 42);
+            let pixel = |x: u32, y: u32| -> Vec<f64> {
+                (0..8).map(|i| sampler.sample(x, y, i, 8, 0)).collect()
+            };
+            assert_eq!(pixel(5, 6), pixel(5, 6));
+            assert_ne!(pixel(5, 6), pixel(6, 5), "{:?}", kind);
+            assert_ne!(pixel(5, 6), pixel(5, 7), "{:?}", kind);
+            let reseeded = Sampler::new(kind, 43).sample(5, 6, 0, 8, 0);
+            assert_ne!(pixel(5, 6)[0], reseeded, "{:?}", kind);
+        }
+    }
+
+    #[test]
+    fn test_from_name() {
+        for kind in SamplerKind::all() {
+            assert_eq!(SamplerKind::from_name(kind.name()), Some(kind));
+        }
+        assert_eq!(SamplerKind::from_name("uniform"), None);
+    }
+}
@@ -0,0 +1,378 @@
+use crate::camera::*;
+use crate::color::*;
+use crate::light::*;
+use crate::material::*;
+use crate::matrix::*;
+use crate::objects::cube::*;
+use crate::objects::plane::*;
+use crate::objects::sphere::*;
+use crate::objects::*;
+use crate::patterns::blend::*;
+use crate::patterns::checker::*;
+use crate::patterns::color_ramp::*;
+use crate::patterns::fractal::*;
+use crate::patterns::gradient::*;
+use crate::patterns::perturbed::*;
+use crate::patterns::radial_gradient::*;
+use crate::patterns::ring::*;
+use crate::patterns::solid_color::*;
+use crate::patterns::stripe::*;
+use crate::patterns::worley::*;
+use crate::patterns::*;
+use crate::point::*;
+use crate::vector3::*;
+use crate::world::*;
+use std::fmt::{self, Display};
+use std::path::{Path, PathBuf};
+use std::str::SplitWhitespace;
+use std::sync::Arc;
+use std::time::SystemTime;
+
+// Scene files have one statement per line, a keyword followed by `name value...` pairs. Lines
+// starting with `#` are comments, colors are sRGB hex codes and angles are in radians.
+//
+//     camera fov 1.047 from 0 1.5 -5 to 0 1 0 up 0 1 0
+//     light position -10 10 -10 color #ffffff
+//     sphere color #1aff80 diffuse 0.7 reflective 0.3 scale 2 2 2 translate -0.5 2 3.5
+//     pattern checker #00ffff #00ebeb scale 0.5 0.5 0.5 rotate_z 0.5236
+//
+// Objects are sphere, plane and cube, transforms are applied in the order they are written. A
+// pattern line paints the object above it, `perturb <factor>` jitters the pattern and
+// `blend <ratio>` mixes it with the pattern the object already has. The fbm, turbulence, ridged,
+// marble and wood patterns blend between their two colors and also take `octaves`,
+// `lacunarity` and `gain`, marble and wood `frequency` and `distortion` too. Worley cells take
+// `output f1|f2|f2-f1`, `metric euclidean|manhattan|chebyshev`, `jitter` and `seed`, and
+// `cells random` or `cells pattern` to color each cell at random or with the object's pattern.
+#[derive(Debug)]
+pub enum SceneError {
+    Io(std::io::Error),
+    Parse { line: usize, message: String },
+}
+
+impl Display for SceneError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            SceneError::Io(e) => write!(f, "{}", e),
+            SceneError::Parse { line, message } => write!(f, "line {}: {}", line, message),
+        }
+    }
+}
+
+pub fn load_scene<P: AsRef<Path>>(
+    path: P,
+    hsize: u32,
+    vsize: u32,
+) -> Result<(Camera, World), SceneError> {
+    let source = std::fs::read_to_string(path).map_err(SceneError::Io)?;
+    parse_scene(&source, hsize, vsize)
+}
+
+// Builds the camera and world described by a scene file, the camera renders at the given size.
+pub fn parse_scene(source: &str, hsize: u32, vsize: u32) -> Result<(Camera, World), SceneError> {
+    let mut cam = None;
+    let mut world = World::new(vec![], vec![]);
+    for (index, line) in source.lines().enumerate() {
+        let mut words = Words(line.split_whitespace());
+        let statement = match words.0.next() {
+            None => continue,
+            Some(keyword) if keyword.starts_with('#') => continue,
+            Some("camera") => parse_camera(&mut words, hsize, vsize).map(|c| cam = Some(c)),
+            Some("light") => parse_light(&mut words).map(|l| world.lights.push(l)),
+            Some(shape @ ("sphere" | "plane" | "cube")) => {
+                parse_object(shape, &mut words).map(|o| world.objects.push(Arc::new(o)))
+            }
+            Some("pattern") => match world.objects.last_mut() {
+                Some(object) => parse_pattern(&mut words, Arc::make_mut(object)),
+                None => Err("pattern before any object".to_string()),
+            },
+            Some(keyword) => Err(format!("unknown statement `{}`", keyword)),
+        };
+        statement.map_err(|message| SceneError::Parse {
+            line: index + 1,
+            message,
+        })?;
+    }
+    let cam = cam.ok_or(SceneError::Parse {
+        line: source.lines().count(),
+        message: "no camera line".to_string(),
+    })?;
+    Ok((cam, world))
+}
+
+struct Words<'a>(SplitWhitespace<'a>);
+
+impl Words<'_> {
+    fn number(&mut self, name: &str) -> Result<f64, String> {
+        match self.0.next() {
+            Some(word) => word
+                .parse()
+                .map_err(|_| format!("`{}` expects a number, got `{}`", name, word)),
+            None => Err(format!("`{}` expects a number", name)),
+        }
+    }
+
+    fn triple(&mut self, name: &str) -> Result<(f64, f64, f64), String> {
+        Ok((self.number(name)?, self.number(name)?, self.number(name)?))
+    }
+
+    fn point(&mut self, name: &str) -> Result<Point, String> {
+        let (x, y, z) = self.triple(name)?;
+        Ok(Point { x, y, z })
+    }
+
+    fn vector(&mut self, name: &str) -> Result<Vector3, String> {
+        let (x, y, z) = self.triple(name)?;
+        Ok(Vector3 { x, y, z })
+    }
+
+    fn color(&mut self, name: &str) -> Result<Color, String> {
+        match self.0.next() {
+            Some(word) => Color::from_hex(word)
+                .ok_or(format!("`{}` expects a hex color, got `{}`", name, word)),
+            None => Err(format!("`{}` expects a hex color", name)),
+        }
+    }
+
+    // Applies `scale`, `translate`, `rotate_x`, `rotate_y`, `rotate_z` and `shear` on top of a
+    // transform, returns false for any other keyword.
+    fn transform(&mut self, keyword: &str, transform: &mut Matrix44) -> Result<bool, String> {
+        *transform = match keyword {
+            "scale" => {
+                let (x, y, z) = self.triple(keyword)?;
+                transform.scale(x, y, z)
+            }
+            "translate" => {
+                let (x, y, z) = self.triple(keyword)?;
+                transform.translate(x, y, z)
+            }
+            "rotate_x" => transform.rotate_x(self.number(keyword)?),
+            "rotate_y" => transform.rotate_y(self.number(keyword)?),
+            "rotate_z" => transform.rotate_z(self.number(keyword)?),
+            "shear" => {
+                let (xy, xz, yx) = self.triple(keyword)?;
+                let (yz, zx, zy) = self.triple(keyword)?;
+                transform.shear(xy, xz, yx, yz, zx, zy)
+            }
+            _ => return Ok(false),
+        };
+        Ok(true)
+    }
+}
+
+fn unknown(statement: &str, keyword: &str) -> String {
+    format!("unknown {} setting `{}`", statement, keyword)
+}
+
+fn parse_camera(words: &mut Words, hsize: u32, vsize: u32) -> Result<Camera, String> {
+    let mut field_of_view = std::f64::consts::PI / 3.0;
+    let mut from = Point {
+        x: 0.0,
+        y: 0.0,
+        z: -5.0,
+    };
+    let mut to = Point::zero();
+    let mut up = Vector3 {
+        x: 0.0,
+        y: 1.0,
+        z: 0.0,
+    };
+    while let Some(keyword) = words.0.next() {
+        match keyword {
+            "fov" => field_of_view = words.number(keyword)?,
+            "from" => from = words.point(keyword)?,
+            "to" => to = words.point(keyword)?,
+            "up" => up = words.vector(keyword)?,
+            _ => return Err(unknown("camera", keyword)),
+        }
+    }
+    let mut cam = Camera::new(hsize, vsize, field_of_view);
+    cam.set_transform(view_transform(from, to, up));
+    Ok(cam)
+}
+
+fn parse_light(words: &mut Words) -> Result<Light, String> {
+    let mut position = None;
+    let mut color = Color::white();
+    while let Some(keyword) = words.0.next() {
+        match keyword {
+            "position" => position = Some(words.point(keyword)?),
+            "color" => color = words.color(keyword)?,
+            _ => return Err(unknown("light", keyword)),
+        }
+    }
+    let position = position.ok_or("light without a position")?;
+    Ok(Light::PointLight(PointLight { position, color }))
+}
+
+fn parse_object(shape: &str, words: &mut Words) -> Result<Object, String> {
+    let mut material = Material::default();
+    let mut transform = Matrix44::identity();
+    while let Some(keyword) = words.0.next() {
+        if words.transform(keyword, &mut transform)? {
+            continue;
+        }
+        match keyword {
+            "color" => material.color = words.color(keyword)?,
+            "ambient" => material.ambient = words.number(keyword)? as f32,
+            "diffuse" => material.diffuse = words.number(keyword)? as f32,
+            "specular" => material.specular = words.number(keyword)? as f32,
+            "shininess" => material.shininess = words.number(keyword)? as f32,
+            "reflective" => material.reflective = words.number(keyword)? as f32,
+            "transparency" => material.transparency = words.number(keyword)? as f32,
+            "refractive_index" => material.refractive_index = words.number(keyword)? as f32,
+            _ => return Err(unknown(shape, keyword)),
+        }
+    }
+    let object = match shape {
+        "sphere" => Object::new(Sphere, material),
+        "plane" => Object::new(Plane, material),
+        _ => Object::new(Cube, material),
+    };
+    Ok(object.set_transform(transform))
+}
+
+fn parse_pattern(words: &mut Words, object: &mut Object) -> Result<(), String> {
+    let kind = words.0.next().ok_or("pattern without a kind")?;
+    let a = words.color(kind)?;
+    let b = words.color(kind)?;
+    let noise = ["fbm", "turbulence", "ridged", "marble", "wood"].contains(&kind);
+    let patterns = [
+        "stripe",
+        "gradient",
+        "radial_gradient",
+        "ring",
+        "checker",
+        "worley",
+    ];
+    if !noise && !patterns.contains(&kind) {
+        return Err(format!("unknown pattern `{}`", kind));
+    }
+    let ramp = ColorRamp::new(a, b);
+    let mut marble = Marble::new(ramp.clone());
+    let mut wood = Wood::new(ramp.clone());
+    let mut worley = Worley::new(CellColor::Distance(ramp.clone()));
+    let mut fractal = match kind {
+        "marble" => marble.fractal.clone(),
+        "wood" => wood.fractal.clone(),
+        "turbulence" => Fractal::new(FractalKind::Turbulence),
+        "ridged" => Fractal::new(FractalKind::Ridged),
+        _ => Fractal::new(FractalKind::Fbm),
+    };
+    let mut transform = Matrix44::identity();
+    let mut perturb = None;
+    let mut blend = None;
+    while let Some(keyword) = words.0.next() {
+        if words.transform(keyword, &mut transform)? {
+            continue;
+        }
+        match keyword {
+            "perturb" => perturb = Some(words.number(keyword)?),
+            "blend" => blend = Some(words.number(keyword)? as f32),
+            "octaves" if noise => fractal.octaves = words.number(keyword)? as u32,
+            "lacunarity" if noise => fractal.lacunarity = words.number(keyword)?,
+            "gain" if noise => fractal.gain = words.number(keyword)?,
+            "frequency" if kind == "marble" => marble.frequency = words.number(keyword)?,
+            "frequency" if kind == "wood" => wood.frequency = words.number(keyword)?,
+            "distortion" if kind == "marble" => marble.distortion = words.number(keyword)?,
+            "distortion" if kind == "wood" => wood.distortion = words.number(keyword)?,
+            "output" if kind == "worley" => {
+                worley.output = match words.0.next() {
+                    Some("f1") => WorleyOutput::F1,
+                    Some("f2") => WorleyOutput::F2,
+                    Some("f2-f1") => WorleyOutput::F2MinusF1,
+                    _ => return Err("`output` expects f1, f2 or f2-f1".to_string()),
+                }
+            }
+            "metric" if kind == "worley" => {
+                worley.metric = match words.0.next() {
+                    Some("euclidean") => Metric::Euclidean,
+                    Some("manhattan") => Metric::Manhattan,
+                    Some("chebyshev") => Metric::Chebyshev,
+                    _ => {
+                        return Err("`metric` expects euclidean, manhattan or chebyshev".to_string())
+                    }
+                }
+            }
+            "jitter" if kind == "worley" => worley.jitter = words.number(keyword)?,
+            "seed" if kind == "worley" => worley.seed = words.number(keyword)? as u32,
+            "cells" if kind == "worley" => {
+                worley.color = match words.0.next() {
+                    Some("random") => CellColor::Random,
+                    Some("pattern") => CellColor::Pattern(Box::new(
+                        object
+                            .material()
+                            .pattern
+                            .clone()
+                            .ok_or("cells pattern on an object without a pattern")?,
+                    )),
+                    _ => return Err("`cells` expects random or pattern".to_string()),
+                }
+            }
+            _ => return Err(unknown("pattern", keyword)),
+        }
+    }
+    let solid = |color| Pattern::new(SolidColor::new(color));
+    let pattern = match kind {
+        "stripe" => Pattern::new(Stripe::new(solid(a), solid(b))),
+        "gradient" => Pattern::new(Gradient::new(solid(a), solid(b))),
+        "radial_gradient" => Pattern::new(RadialGradient::new(solid(a), solid(b))),
+        "ring" => Pattern::new(Ring::new(solid(a), solid(b))),
+        "checker" => Pattern::new(Checker::new(solid(a), solid(b))),
+        "marble" => Pattern::new(Marble { fractal, ..marble }),
+        "wood" => Pattern::new(Wood { fractal, ..wood }),
+        "worley" => Pattern::new(worley),
+        _ => Pattern::new(FractalNoise::new(fractal, ramp)),
+    };
+    let mut pattern = pattern.set_transform(transform);
+    if let Some(factor) = perturb {
+        pattern = Pattern::new(Perturbed::new(pattern, factor));
+    }
+    let mut material = object.material().clone();
+    if let Some(ratio) = blend {
+        let base = material
+            .pattern
+            .take()
+            .ok_or("blend on an object without a pattern")?;
+        pattern = Pattern::new(Blend::new(base, pattern, ratio));
+    }
+    material.pattern = Some(pattern);
+    object.set_material(material);
+    Ok(())
+}
+
+// Polls a scene file for changes, cheap enough to call on every frame.
+pub struct SceneWatcher {
+    path: PathBuf,
+    modified: Option<SystemTime>,
+}
+
+impl SceneWatcher {
+    pub fn new<P: AsRef<Path>>(path: P) -> Self {
+        let path = path.as_ref().to_path_buf();
+        let modified = modified_time(&path);
+        SceneWatcher { path, modified }
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    // True once after every save of the file.
+    pub fn changed(&mut self) -> bool {
+        let modified = modified_time(&self.path);
+        if modified == self.modified {
+            return false;
+        }
+        self.modified = modified;
+        // Editors replacing the file can leave it missing for a moment, wait for the new one.
+        modified.is_some()
+    }
+}
+
+fn modified_time(path: &Path) -> Option<SystemTime> {
+    std::fs::metadata(path).and_then(|m| m.modified()).ok()
+}
+
+#[cfg(test)]
+#[path = "./scene_tests.rs"]
+mod scene_tests;
@@ -0,0 +1,197 @@
+#[cfg(test)]
+mod tests {
+    use crate::navigation::*;
+    use crate::scene::*;
+
+    fn parse_error(source: &str) -> (usize, String) {
+        match parse_scene(source, 10, 10) {
+            Err(SceneError::Parse { line, message }) => (line, message),
+            Err(e) => panic!("unexpected error {}", e),
+            Ok(_) => panic!("{:?} parsed", source),
+        }
+    }
+
+    #[test]
+    fn test_parse_scene() {
+        let source = "
+            # A lit sphere.
+            camera fov 1.5 from 0 0 -5 to 0 0 0 up 0 1 0
+            light position -10 10 -10 color #ff0000
+            sphere color #ffffff reflective 0.5 scale 2 2 2 translate 0 1 0
+        ";
+        let (cam, world) = parse_scene(source, 20, 10).unwrap();
+        assert_eq!((cam.hsize, cam.vsize, cam.field_of_view), (20, 10, 1.5));
+        assert_eq!(
+            *cam.transform(),
+            view_transform(
+                Point {
+                    x: 0.0,
+                    y: 0.0,
+                    z: -5.0,
+                },
+                Point::zero(),
+                Vector3 {
+                    x: 0.0,
+                    y: 1.0,
+                    z: 0.0,
+                },
+            )
+        );
+        assert_eq!(world.lights.len(), 1);
+        assert_eq!(world.lights[0].color(), Color::red());
+        assert_eq!(world.objects.len(), 1);
+        assert_eq!(world.objects[0].name(), "sphere");
+        assert_eq!(world.objects[0].material().color, Color::white());
+        assert_eq!(world.objects[0].material().reflective, 0.5);
+        assert_eq!(
+            *world.objects[0].transform(),
+            Matrix44::scaling(2.0, 2.0, 2.0).translate(0.0, 1.0, 0.0)
+        );
+    }
+
+    #[test]
+    fn test_camera_line_round_trips() {
+        let mut navigation = Navigation::from_camera(&Camera::new(10, 10, 1.0), 5.0);
+        navigation.orbit(0.3, 0.2);
+        let (cam, _) = parse_scene(&navigation.scene_line(0.8), 10, 10).unwrap();
+        assert_eq!(cam.field_of_view, 0.8);
+        assert_eq!(*cam.transform(), navigation.transform());
+    }
+
+    #[test]
+    fn test_patterns() {
+        let source = "
+            camera
+            plane
+            pattern stripe #000 #fff scale 0.5 0.5 0.5
+            pattern checker #f00 #0f0 perturb 0.2 blend 0.3
+        ";
+        let (_, world) = parse_scene(source, 10, 10).unwrap();
+        let pattern = format!("{:?}", world.objects[0].material().pattern);
+        assert!(
+            pattern.starts_with("Some(Pattern { function: Blend"),
+            "expected a blend, got {}",
+            pattern
+        );
+    }
+
+    #[test]
+    fn test_noise_patterns() {
+        let source = "
+            camera
+            sphere
+            pattern marble #fff #333 octaves 6 gain 0.6 frequency 2 distortion 0.5
+            cube
+            pattern wood #a06030 #603010 lacunarity 2.5 scale 0.2 0.2 0.2
+            plane
+            pattern ridged #000 #fff octaves 3
+        ";
+        let (_, world) = parse_scene(source, 10, 10).unwrap();
+        let pattern = |i: usize| format!("{:?}", world.objects[i].material().pattern);
+        assert!(pattern(0).contains(
+            "Marble { fractal: Fractal { kind: Turbulence, octaves: 6, lacunarity: 2.0, gain: 0.6"
+        ));
+        assert!(pattern(0).contains("frequency: 2.0, distortion: 0.5 }"));
+        assert!(
+            pattern(1).contains("Wood { fractal: Fractal { kind: Fbm, octaves: 2, lacunarity: 2.5")
+        );
+        assert!(pattern(2).contains("FractalNoise { fractal: Fractal { kind: Ridged, octaves: 3"));
+        assert_eq!(
+            parse_error("camera\nplane\npattern stripe #000 #fff octaves 2"),
+            (3, "unknown pattern setting `octaves`".to_string())
+        );
+        assert_eq!(
+            parse_error("camera\nplane\npattern ridged #000 #fff frequency 2"),
+            (3, "unknown pattern setting `frequency`".to_string())
+        );
+    }
+
+    #[test]
+    fn test_worley_pattern() {
+        let source = "
+            camera
+            sphere
+            pattern worley #000 #fff output f2-f1 metric chebyshev jitter 0.5 seed 3
+            cube
+            pattern checker #f00 #0f0
+            pattern worley #000 #fff cells pattern scale 0.1 0.1 0.1
+        ";
+        let (_, world) = parse_scene(source, 10, 10).unwrap();
+        let pattern = |i: usize| format!("{:?}", world.objects[i].material().pattern);
+        assert!(pattern(0).contains("Worley { output: F2MinusF1, metric: Chebyshev, jitter: 0.5"));
+        assert!(pattern(0).contains("seed: 3 }"));
+        assert!(pattern(1).contains("Worley { output: F1, metric: Euclidean, jitter: 1.0, color: Pattern(Pattern { function: Checker"));
+        assert_eq!(
+            parse_error("camera\nplane\npattern worley #000 #fff cells pattern"),
+            (
+                3,
+                "cells pattern on an object without a pattern".to_string()
+            )
+        );
+        assert_eq!(
+            parse_error("camera\nplane\npattern worley #000 #fff metric taxicab"),
+            (
+                3,
+                "`metric` expects euclidean, manhattan or chebyshev".to_string()
+            )
+        );
+    }
+
+    #[test]
+    fn test_errors_point_at_the_line() {
+        assert_eq!(
+            parse_error("camera\nsphere diffuse high"),
+            (2, "`diffuse` expects a number, got `high`".to_string())
+        );
+        assert_eq!(
+            parse_error("camera\n\ncube glow 1"),
+            (3, "unknown cube setting `glow`".to_string())
+        );
+        assert_eq!(
+            parse_error("pattern stripe #000 #fff"),
+            (1, "pattern before any object".to_string())
+        );
+        assert_eq!(
+            parse_error("camera\nsphere\npattern stripe #000 #fff blend 0.5"),
+            (3, "blend on an object without a pattern".to_string())
+        );
+        assert_eq!(
+            parse_error("camera\nlight color #fff"),
+            (2, "light without a position".to_string())
+        );
+        assert_eq!(parse_error("sphere").1, "no camera line");
+        assert_eq!(parse_error("teapot").1, "unknown statement `teapot`");
+    }
+
+    #[test]
+    fn test_example_scene() {
+        let (cam, world) = parse_scene(include_str!("../scenes/example.scene"), 40, 40).unwrap();
+        let (builtin_cam, builtin_world) = crate::demo::get_scene();
+        assert_eq!(cam.transform(), builtin_cam.transform());
+        assert!((cam.field_of_view - builtin_cam.field_of_view).abs() < 1e-6);
+        assert_eq!(world.lights.len(), builtin_world.lights.len());
+        let names: Vec<&str> = world.objects.iter().map(|o| o.name()).collect();
+        let builtin: Vec<&str> = builtin_world.objects.iter().map(|o| o.name()).collect();
+        assert_eq!(names, builtin);
+    }
+
+    #[test]
+    fn test_watcher() {
+        let path =
+            std::env::temp_dir().join(format!("rustracer_watch_{}.scene", std::process::id()));
+        std::fs::write(&path, "camera").unwrap();
+        let mut watcher = SceneWatcher::new(&path);
+        assert!(!watcher.changed());
+        let file = std::fs::File::options().write(true).open(&path).unwrap();
+        file.set_modified(SystemTime::now() + std::time::Duration::from_secs(10))
+            .unwrap();
+        assert!(watcher.changed());
+        assert!(!watcher.changed());
+        std::fs::remove_file(&path).unwrap();
+        // A missing file is not a change, its replacement is.
+        assert!(!watcher.changed());
+        std::fs::write(&path, "camera").unwrap();
+        assert!(watcher.changed());
+        std::fs::remove_file(&path).unwrap();
+    }
+}
@@ -0,0 +1,245 @@
+use std::cell::{Cell, RefCell};
+use std::fmt::{self, Display};
+use std::time::{Duration, Instant};
+
+// Counters of the work done by renders on the calling thread, to find out where the time goes.
+// Tracing functions report to them as they go, `take` reads them back and starts over.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum RayCategory {
+    Primary,
+    Shadow,
+    Reflection,
+    Refraction,
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct RenderStats {
+    pub primary_rays: u64,
+    pub shadow_rays: u64,
+    pub reflection_rays: u64,
+    pub refraction_rays: u64,
+    // Ray-object tests per kind of shape, in the order the shapes were first tested.
+    pub intersection_tests: Vec<(&'static str, u64)>,
+    // Deepest bounce reached from each primary ray, added up.
+    pub recursion_depth_total: u64,
+    // Wall clock time per phase, in the order the phases first ran.
+    pub phases: Vec<(&'static str, Duration)>,
+}
+
+impl RenderStats {
+    pub fn rays(&self) -> u64 {
+        self.primary_rays + self.shadow_rays + self.reflection_rays + self.refraction_rays
+    }
+
+    pub fn total_intersection_tests(&self) -> u64 {
+        self.intersection_tests.iter().map(|(_, tests)| tests).sum()
+    }
+
+    pub fn average_recursion_depth(&self) -> f64 {
+        if self.primary_rays == 0 {
+            return 0.0;
+        }
+        self.recursion_depth_total as f64 / self.primary_rays as f64
+    }
+
+    pub fn phase(&self, name: &str) -> Duration {
+        self.phases
+            .iter()
+            .find(|(phase, _)| *phase == name)
+            .map_or(Duration::ZERO, |(_, time)| *time)
+    }
+
+    // Rays of every kind traced per second of the render phase.
+    pub fn rays_per_second(&self) -> f64 {
+        let seconds = self.phase("render").as_secs_f64();
+        if seconds == 0.0 {
+            return 0.0;
+        }
+        self.rays() as f64 / seconds
+    }
+
+    pub fn to_json(&self) -> String {
+        let tests: Vec<String> = self
+            .intersection_tests
+            .iter()
+            .map(|(shape, tests)| format!("{}: {}", json_string(shape), tests))
+            .collect();
+        let phases: Vec<String> = self
+            .phases
+            .iter()
+            .map(|(phase, time)| format!("{}: {:.6}", json_string(phase), time.as_secs_f64()))
+            .collect();
+        format!(
+            "{{\n  \"rays\": {{\"primary\": {}, \"shadow\": {}, \"reflection\": {}, \"refraction\": {}}},\n  \"intersection_tests\": {{{}}},\n  \"average_recursion_depth\": {:.4},\n  \"phase_seconds\": {{{}}},\n  \"rays_per_second\": {:.1}\n}}\n",
+            self.primary_rays,
+            self.shadow_rays,
+            self.reflection_rays,
+            self.refraction_rays,
+            tests.join(", "),
+            self.average_recursion_depth(),
+            phases.join(", "),
+            self.rays_per_second()
+        )
+    }
+}
+
+// Quoted JSON string, shape and phase names come from code but may hold anything.
+fn json_string(text: &str) -> String {
+    let mut quoted = String::from("\"");
+    for c in text.chars() {
+        match c {
+            '"' => quoted.push_str("\\\""),
+            '\\' => quoted.push_str("\\\\"),
+            c if c.is_control() => quoted.push_str(&format!("\\u{:04x}", c as u32)),
+            c => quoted.push(c),
+        }
+    }
+    quoted.push('"');
+    quoted
+}
+
+impl Display for RenderStats {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "rays")?;
+        for (kind, count) in [
+            ("primary", self.primary_rays),
+            ("shadow", self.shadow_rays),
+            ("reflection", self.reflection_rays),
+            ("refraction", self.refraction_rays),
+        ] {
+            writeln!(f, "  {:<20} {:>14}", kind, count)?;
+        }
+        writeln!(f, "intersection tests")?;
+        for (shape, tests) in &self.intersection_tests {
+            writeln!(f, "  {:<20} {:>14}", shape, tests)?;
+        }
+        writeln!(
+            f,
+            "average recursion depth {:>11.3}",
+            self.average_recursion_depth()
+        )?;
+        writeln!(f, "time")?;
+        for (phase, time) in &self.phases {
+            writeln!(f, "  {:<20} {:>12.3} s", phase, time.as_secs_f64())?;
+        }
+        write!(f, "rays per second {:>19.0}", self.rays_per_second())
+    }
+}
+
+struct Collector {
+    stats: RenderStats,
+    // Bounces between the current ray and the camera, and the deepest one since the last
+    // primary ray.
+    depth: u32,
+    deepest: u32,
+}
+
+thread_local! {
+    static COLLECTOR: RefCell<Collector> = const {
+        RefCell::new(Collector {
+            stats: RenderStats {
+                primary_rays: 0,
+                shadow_rays: 0,
+                reflection_rays: 0,
+                refraction_rays: 0,
+                intersection_tests: Vec::new(),
+                recursion_depth_total: 0,
+                phases: Vec::new(),
+            },
+            depth: 0,
+            deepest: 0,
+        })
+    };
+    static COUNT_TESTS: Cell<bool> = const { Cell::new(false) };
+}
+
+fn with<T>(f: impl FnOnce(&mut Collector) -> T) -> T {
+    COLLECTOR.with(|collector| f(&mut collector.borrow_mut()))
+}
+
+// Counts a ray, camera rays and bounces go through `primary` and `bounce` instead.
+pub fn ray(kind: RayCategory) {
+    with(|c| match kind {
+        RayCategory::Primary => c.stats.primary_rays += 1,
+        RayCategory::Shadow => c.stats.shadow_rays += 1,
+        RayCategory::Reflection => c.stats.reflection_rays += 1,
+        RayCategory::Refraction => c.stats.refraction_rays += 1,
+    });
+}
+
+// Traces a camera ray with `trace`, keeping track of how deep its bounces go.
+pub fn primary<T>(trace: impl FnOnce() -> T) -> T {
+    with(|c| {
+        c.depth = 0;
+        c.deepest = 0;
+    });
+    ray(RayCategory::Primary);
+    let result = trace();
+    with(|c| c.stats.recursion_depth_total += c.deepest as u64);
+    result
+}
+
+// Traces a reflected or refracted ray with `trace`, one level deeper than the current ray.
+pub fn bounce<T>(kind: RayCategory, trace: impl FnOnce() -> T) -> T {
+    ray(kind);
+    with(|c| {
+        c.depth += 1;
+        c.deepest = c.deepest.max(c.depth);
+    });
+    let result = trace();
+    with(|c| c.depth -= 1);
+    result
+}
+
+// Intersection tests sit in the innermost loop of every render, they are only counted on threads
+// that asked for them, like the exporter before it renders.
+pub fn count_intersection_tests() {
+    COUNT_TESTS.with(|on| on.set(true));
+}
+
+pub fn intersection_test(shape: &'static str) {
+    if !COUNT_TESTS.with(Cell::get) {
+        return;
+    }
+    with(|c| {
+        let tests = &mut c.stats.intersection_tests;
+        // Shapes pass the same literal every time, the address finds it without comparing text.
+        let found = tests
+            .iter()
+            .position(|(name, _)| std::ptr::eq(*name, shape))
+            .or_else(|| tests.iter().position(|(name, _)| *name == shape));
+        match found {
+            Some(index) => tests[index].1 += 1,
+            None => tests.push((shape, 1)),
+        }
+    });
+}
+
+// Running count of intersection tests on the calling thread.
+pub fn intersection_tests() -> u64 {
+    with(|c| c.stats.total_intersection_tests())
+}
+
+// Runs `f`, adding the time it takes to the phase.
+pub fn phase<T>(name: &'static str, f: impl FnOnce() -> T) -> T {
+    let start = Instant::now();
+    let result = f();
+    let elapsed = start.elapsed();
+    with(|c| {
+        let phases = &mut c.stats.phases;
+        match phases.iter_mut().find(|(phase, _)| *phase == name) {
+            Some((_, time)) => *time += elapsed,
+            None => phases.push((name, elapsed)),
+        }
+    });
+    result
+}
+
+// Statistics gathered on this thread since the last call.
+pub fn take() -> RenderStats {
+    with(|c| std::mem::take(&mut c.stats))
+}
+
+#[cfg(test)]
+#[path = "./stats_tests.rs"]
+mod stats_tests;
@@ -0,0 +1,118 @@
+#[cfg(test)]
+mod tests {
+    use crate::camera::*;
+    use crate::material::*;
+    use crate::objects::sphere::*;
+    use crate::objects::*;
+    use crate::render::*;
+    use crate::stats::*;
+    use crate::world::*;
+    use std::time::Duration;
+
+    // Tests run on their own threads, so every test starts from empty counters.
+    #[test]
+    fn test_render_counts_rays_and_tests() {
+        let world = World::default();
+        count_intersection_tests();
+        render(
+            &Camera::test_camera(5, 0.5),
+            &world,
+            &RenderSettings::default(),
+        );
+        let stats = take();
+        assert_eq!(stats.primary_rays, 25);
+        assert!(stats.shadow_rays > 0 && stats.shadow_rays <= 25);
+        assert_eq!(stats.reflection_rays, 0);
+        assert_eq!(stats.refraction_rays, 0);
+        // Both spheres are tested by every ray.
+        assert_eq!(
+            stats.intersection_tests,
+            vec![("sphere", 2 * (stats.primary_rays + stats.shadow_rays))]
+        );
+        assert_eq!(take(), RenderStats::default());
+    }
+
+    #[test]
+    fn test_intersection_tests_are_opt_in() {
+        render(
+            &Camera::test_camera(5, 0.5),
+            &World::default(),
+            &RenderSettings::default(),
+        );
+        let stats = take();
+        assert_eq!(stats.primary_rays, 25);
+        assert!(stats.intersection_tests.is_empty());
+    }
+
+    #[test]
+    fn test_reflections_are_counted_with_their_depth() {
+        let mirror = Material {
+            reflective: 1.0,
+            ..Material::default()
+        };
+        let world = World::new(vec![Object::new(Sphere, mirror)], World::default().lights);
+        let settings = RenderSettings {
+            max_recursion: 3,
+            ..RenderSettings::default()
+        };
+        render(&Camera::test_camera(1, 0.5), &world, &settings);
+        let stats = take();
+        // A convex mirror does not see itself, the only bounce leaves the sphere.
+        assert_eq!(stats.reflection_rays, 1);
+        assert_eq!(stats.average_recursion_depth(), 1.0);
+    }
+
+    #[test]
+    fn test_average_depth_is_per_primary_ray() {
+        primary(|| {
+            bounce(RayCategory::Refraction, || {
+                bounce(RayCategory::Reflection, || ())
+            })
+        });
+        primary(|| ());
+        let stats = take();
+        assert_eq!(stats.primary_rays, 2);
+        assert_eq!(stats.reflection_rays, 1);
+        assert_eq!(stats.refraction_rays, 1);
+        assert_eq!(stats.average_recursion_depth(), 1.0);
+    }
+
+    #[test]
+    fn test_phases_add_up() {
+        phase("render", || std::thread::sleep(Duration::from_millis(2)));
+        phase("save", || ());
+        phase("render", || std::thread::sleep(Duration::from_millis(2)));
+        let stats = take();
+        assert_eq!(stats.phases.len(), 2);
+        assert!(stats.phase("render") >= Duration::from_millis(4));
+        assert_eq!(stats.phase("scene"), Duration::ZERO);
+    }
+
+    #[test]
+    fn test_json() {
+        let stats = RenderStats {
+            primary_rays: 4,
+            shadow_rays: 2,
+            intersection_tests: vec![("sphere", 12), ("plane", 6)],
+            recursion_depth_total: 2,
+            phases: vec![("render", Duration::from_millis(500))],
+            ..RenderStats::default()
+        };
+        let json = stats.to_json();
+        assert!(json.contains(
+            "\"rays\": {\"primary\": 4, \"shadow\": 2, \"reflection\": 0, \"refraction\": 0}"
+        ));
+        assert!(json.contains("\"intersection_tests\": {\"sphere\": 12, \"plane\": 6}"));
+        assert!(json.contains("\"average_recursion_depth\": 0.5000"));
+        assert!(json.contains("\"phase_seconds\": {\"render\": 0.500000}"));
+        assert!(json.contains("\"rays_per_second\": 12.0"));
+        // Names are escaped.
+        let odd = RenderStats {
+            phases: vec![("a \"b\" \\ c\n", Duration::ZERO)],
+            ..RenderStats::default()
+        };
+        assert!(odd
+            .to_json()
+            .contains("\"phase_seconds\": {\"a \\\"b\\\" \\\\ c\\u000a\": 0.000000}"));
+    }
+}
@@ -0,0 +1,77 @@
+use crate::color::*;
+use crate::framebuffer::*;
+use image::RgbaImage;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ToneMapper {
+    // Anything above 1.0 is cut, the historical behaviour.
+    Clamp,
+    // c / (1 + c), never reaches white.
+    Reinhard,
+    // Reinhard with a white point, values at or above `white` map to 1.0.
+    ExtendedReinhard { white: f32 },
+    // Narkowicz fit of the ACES filmic curve.
+    AcesFilmic,
+}
+
+impl ToneMapper {
+    pub fn map(&self, channel: f32) -> f32 {
+        let c = channel.max(0.0);
+        let mapped = match *self {
+            ToneMapper::Clamp => c,
+            ToneMapper::Reinhard => c / (1.0 + c),
+            ToneMapper::ExtendedReinhard { white } => c * (1.0 + c / (white * white)) / (1.0 + c),
+            ToneMapper::AcesFilmic => (c * (2.51 * c + 0.03)) / (c * (2.43 * c + 0.59) + 0.14),
+        };
+        mapped.clamp(0.0, 1.0)
+    }
+}
+
+// Display transform applied to the linear render before it is quantized to 8 bits, the window and
+// 8 bit files go through it while float files keep the scene values.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct PostProcess {
+    // Exposure compensation in stops, each stop doubles the light.
+    pub exposure: f32,
+    pub tone_mapper: ToneMapper,
+    // Dither when quantizing to 8 bits.
+    pub dither: bool,
+}
+
+impl Default for PostProcess {
+    fn default() -> Self {
+        PostProcess {
+            exposure: 0.0,
+            tone_mapper: ToneMapper::Clamp,
+            dither: false,
+        }
+    }
+}
+
+impl PostProcess {
+    pub fn apply(&self, color: &Color) -> Color {
+        let scaled = *color * 2.0_f32.powf(self.exposure);
+        Color {
+            red: self.tone_mapper.map(scaled.red),
+            green: self.tone_mapper.map(scaled.green),
+            blue: self.tone_mapper.map(scaled.blue),
+        }
+    }
+
+    pub fn apply_framebuffer(&self, framebuffer: &Framebuffer) -> Framebuffer {
+        Framebuffer {
+            width: framebuffer.width,
+            height: framebuffer.height,
+            pixels: framebuffer.pixels.iter().map(|c| self.apply(c)).collect(),
+        }
+    }
+
+    // Display ready 8 bit sRGB image of a linear render.
+    pub fn to_rgba8(self, framebuffer: &Framebuffer) -> RgbaImage {
+        self.apply_framebuffer(framebuffer).to_rgba8(self.dither)
+    }
+}
+
+#[cfg(test)]
+#[path = "./tonemap_tests.rs"]
+mod tonemap_tests;
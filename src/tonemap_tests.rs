@@ -0,0 +1,90 @@
+#[cfg(test)]
+mod tests {
+    use crate::tonemap::*;
+
+    #[test]
+    fn test_clamp() {
+        assert_eq!(ToneMapper::Clamp.map(0.5), 0.5);
+        assert_eq!(ToneMapper::Clamp.map(3.0), 1.0);
+        assert_eq!(ToneMapper::Clamp.map(-1.0), 0.0);
+    }
+
+    #[test]
+    fn test_reinhard() {
+        assert_eq!(ToneMapper::Reinhard.map(1.0), 0.5);
+        assert_eq!(ToneMapper::Reinhard.map(3.0), 0.75);
+        assert!(ToneMapper::Reinhard.map(1000.0) < 1.0);
+    }
+
+    #[test]
+    fn test_extended_reinhard() {
+        let tone_mapper = ToneMapper::ExtendedReinhard { white: 4.0 };
+        assert_eq!(tone_mapper.map(4.0), 1.0);
+        assert_eq!(tone_mapper.map(8.0), 1.0);
+        assert!(tone_mapper.map(1.0) > ToneMapper::Reinhard.map(1.0));
+    }
+
+    #[test]
+    fn test_aces_filmic() {
+        assert_eq!(ToneMapper::AcesFilmic.map(0.0), 0.0);
+        assert_eq!(ToneMapper::AcesFilmic.map(100.0), 1.0);
+        let mut previous = 0.0;
+        for i in 1..100 {
+            let value = ToneMapper::AcesFilmic.map(i as f32 * 0.1);
+            assert!(value >= previous);
+            previous = value;
+        }
+    }
+
+    #[test]
+    fn test_exposure() {
+        let post_process = PostProcess {
+            exposure: 1.0,
+            tone_mapper: ToneMapper::Clamp,
+            dither: false,
+        };
+        assert_eq!(
+            post_process.apply(&Color {
+                red: 0.25,
+                green: 0.5,
+                blue: 0.1,
+            }),
+            Color {
+                red: 0.5,
+                green: 1.0,
+                blue: 0.2,
+            }
+        );
+    }
+
+    #[test]
+    fn test_default_is_clamp() {
+        let color = Color {
+            red: 2.0,
+            green: 0.3,
+            blue: -0.5,
+        };
+        assert_eq!(
+            PostProcess::default().apply(&color),
+            Color {
+                red: 1.0,
+                green: 0.3,
+                blue: 0.0,
+            }
+        );
+    }
+
+    #[test]
+    fn test_apply_framebuffer() {
+        let mut framebuffer = Framebuffer::new(2, 1);
+        framebuffer.set(1, 0, Color::white());
+        let post_process = PostProcess {
+            exposure: 0.0,
+            tone_mapper: ToneMapper::Reinhard,
+            dither: false,
+        };
+        let mapped = post_process.apply_framebuffer(&framebuffer);
+        assert_eq!(mapped.get(0, 0), Color::black());
+        assert_eq!(mapped.get(1, 0), Color::white() * 0.5);
+    }
+}
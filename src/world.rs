@@ -6,36 +6,48 @@ use crate::objects::sphere::*;
 use crate::objects::*;
 use crate::point::*;
 
+use std::sync::Arc;
+
 pub struct World {
-    pub objects: Vec<Object>,
+    // Shared so intersections can point back at the objects they hit.
+    pub objects: Vec<Arc<Object>>,
     pub lights: Vec<Light>,
 }
 
 impl World {
     pub fn new(objects: Vec<Object>, lights: Vec<Light>) -> Self {
-        World { objects, lights }
+        World {
+            objects: objects.into_iter().map(Arc::new).collect(),
+            lights,
+        }
     }
 }
 
 impl Default for World {
     fn default() -> Self {
-        let sphere1 = Sphere::new(&Material {
-            color: Color {
-                red: 0.8,
-                green: 1.0,
-                blue: 0.6,
+        let sphere1 = Object::new(
+            Sphere,
+            Material {
+                color: Color {
+                    red: 0.8,
+                    green: 1.0,
+                    blue: 0.6,
+                },
+                diffuse: 0.7,
+                specular: 0.2,
+                ..Material::default()
+            },
+        );
+        let sphere2 = Object::new(
+            Sphere,
+            Material {
+                color: Color::white(),
+                ..Material::default()
             },
-            diffuse: 0.7,
-            specular: 0.2,
-            ..Material::default()
-        });
-        let mut sphere2 = Sphere::new(&Material {
-            color: Color::white(),
-            ..Material::default()
-        });
-        sphere2.set_transform(Matrix44::scaling(0.5, 0.5, 0.5));
+        )
+        .set_transform(Matrix44::scaling(0.5, 0.5, 0.5));
         World {
-            objects: vec![Object::Sphere(sphere1), Object::Sphere(sphere2)],
+            objects: vec![Arc::new(sphere1), Arc::new(sphere2)],
             lights: vec![Light::PointLight(PointLight {
                 position: Point {
                     x: -10.0,
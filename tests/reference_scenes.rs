@@ -0,0 +1,134 @@
+// Renders small scenes and compares them with the images in tests/reference, to catch shading
+// changes the unit tests miss. After an intended change, run with `UPDATE_REFERENCE=1` to write
+// new references and look at them before committing. Failing scenes leave their render and a
+// heatmap of the differences in target/tmp/reference.
+use rustracer::debug_view::*;
+use rustracer::demo;
+use rustracer::image_diff::*;
+use rustracer::matrix::*;
+use rustracer::point::*;
+use rustracer::sampler::*;
+use rustracer::scene::*;
+use rustracer::tonemap::*;
+use rustracer::vector3::*;
+use rustracer::{render, Camera, RenderSettings, World};
+use std::path::Path;
+
+fn check(name: &str, cam: &Camera, world: &World, settings: &RenderSettings) {
+    let actual = settings.display().to_rgba8(&render(cam, world, settings));
+    let reference = Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("tests/reference")
+        .join(format!("{}.png", name));
+    if std::env::var_os("UPDATE_REFERENCE").is_some() {
+        actual.save(&reference).unwrap();
+        return;
+    }
+    let expected = image::open(&reference)
+        .unwrap_or_else(|e| {
+            panic!(
+                "{}: {}, UPDATE_REFERENCE=1 writes it",
+                reference.display(),
+                e
+            )
+        })
+        .to_rgba8();
+    let diff = ImageDiff::new(&expected, &actual).unwrap_or_else(|e| panic!("{}: {}", name, e));
+    let tolerance = Tolerance::default();
+    if !tolerance.accepts(&diff) {
+        let failures = Path::new(env!("CARGO_TARGET_TMPDIR")).join("reference");
+        std::fs::create_dir_all(&failures).unwrap();
+        actual
+            .save(failures.join(format!("{}_actual.png", name)))
+            .unwrap();
+        diff.heatmap(tolerance.max)
+            .save(failures.join(format!("{}_diff.png", name)))
+            .unwrap();
+        panic!(
+            "{} differs from its reference: max channel error {}, {} pixels off by more than {}, \
+             see {}",
+            name,
+            diff.max_channel_error(),
+            diff.differing_pixels(tolerance.channel),
+            tolerance.channel,
+            failures.display()
+        );
+    }
+}
+
+fn demo_camera(size: u32) -> (Camera, World) {
+    let (cam, world) = demo::get_scene();
+    (cam.resized(size, size), world)
+}
+
+#[test]
+fn default_world() {
+    let mut cam = Camera::new(48, 48, std::f64::consts::FRAC_PI_3);
+    cam.set_transform(view_transform(
+        Point {
+            x: 0.0,
+            y: 1.0,
+            z: -5.0,
+        },
+        Point::zero(),
+        Vector3 {
+            x: 0.0,
+            y: 1.0,
+            z: 0.0,
+        },
+    ));
+    check(
+        "default_world",
+        &cam,
+        &World::default(),
+        &RenderSettings::default(),
+    );
+}
+
+// Patterns, reflection, refraction, planes and cubes.
+#[test]
+fn demo_scene() {
+    let (cam, world) = demo_camera(96);
+    check("demo_scene", &cam, &world, &RenderSettings::default());
+}
+
+#[test]
+fn example_scene_file() {
+    let path = Path::new(env!("CARGO_MANIFEST_DIR")).join("scenes/example.scene");
+    let (cam, world) = load_scene(path, 64, 64).unwrap();
+    check("example_scene", &cam, &world, &RenderSettings::default());
+}
+
+#[test]
+fn tone_mapped() {
+    let (cam, world) = demo_camera(64);
+    let settings = RenderSettings {
+        post_process: PostProcess {
+            exposure: 1.5,
+            tone_mapper: ToneMapper::AcesFilmic,
+            ..PostProcess::default()
+        },
+        ..RenderSettings::default()
+    };
+    check("tone_mapped", &cam, &world, &settings);
+}
+
+#[test]
+fn normals_view() {
+    let (cam, world) = demo_camera(64);
+    let settings = RenderSettings {
+        view: DebugView::Normals,
+        ..RenderSettings::default()
+    };
+    check("normals_view", &cam, &world, &settings);
+}
+
+#[test]
+fn antialiased() {
+    let (cam, world) = demo_camera(64);
+    let settings = RenderSettings {
+        samples: 4,
+        sampler: Sampler::new(SamplerKind::Sobol, 1),
+        ..RenderSettings::default()
+    };
+    check("antialiased", &cam, &world, &settings);
+}